@@ -8,14 +8,18 @@ import requests
 import time
 import random
 import sys
+import os
 
 API_BASE = "http://localhost:8080/api"
+# Token organisateur (POST /api/admin/token), requis pour créer une partie
+ADMIN_TOKEN = os.environ.get("POKER_ADMIN_TOKEN")
 
 class PokerBot:
     def __init__(self, name, game_id=None):
         self.name = name
         self.game_id = game_id
         self.player_id = None
+        self.auth_token = None
         
     def create_game(self):
         """Créer une nouvelle partie"""
@@ -27,7 +31,15 @@ class PokerBot:
             "big_blind": 20
         }
         
-        response = requests.post(f"{API_BASE}/games", json=data)
+        if not ADMIN_TOKEN:
+            print("❌ Créer une partie demande un token admin (variable POKER_ADMIN_TOKEN)")
+            return False
+
+        response = requests.post(
+            f"{API_BASE}/games",
+            json=data,
+            headers={"Authorization": f"Bearer {ADMIN_TOKEN}"}
+        )
         if response.status_code == 200:
             result = response.json()
             self.game_id = result["game_id"]
@@ -51,6 +63,7 @@ class PokerBot:
         if response.status_code == 200:
             result = response.json()
             self.player_id = result["player_id"]
+            self.auth_token = result["auth_token"]
             print(f"✅ Rejoint la partie en position {result['position']}")
             print(f"   Player ID: {self.player_id}")
             return True
@@ -65,7 +78,7 @@ class PokerBot:
             
         response = requests.get(
             f"{API_BASE}/games/{self.game_id}/state",
-            params={"player_id": self.player_id}
+            headers={"Authorization": f"Bearer {self.auth_token}"}
         )
         
         if response.status_code == 200:
@@ -83,7 +96,7 @@ class PokerBot:
             action["amount"] = amount
             
         data = {
-            "player_id": self.player_id,
+            "auth_token": self.auth_token,
             "action": action
         }
         
@@ -214,7 +227,7 @@ def main():
     
     print(f"\n⏳ En attente d'autres joueurs...")
     print(f"   Utilisez la commande suivante pour démarrer la partie:")
-    print(f"   curl -X POST http://localhost:8080/api/games/{bot.game_id}/start")
+    print(f"   curl -X POST -H \"Authorization: Bearer $POKER_ADMIN_TOKEN\" http://localhost:8080/api/games/{bot.game_id}/start")
     
     # Jouer
     bot.play()
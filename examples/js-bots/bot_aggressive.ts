@@ -62,7 +62,7 @@ class AggressiveBot {
 
         try {
             const response = await axios.get(`${API_BASE}/games/${this.gameId}/state`, {
-                params: { player_id: this.playerId }
+                headers: { Authorization: `Bearer ${this.authToken}` }
             });
             return response.data;
         } catch (error) {
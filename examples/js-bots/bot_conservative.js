@@ -1,6 +1,8 @@
 const axios = require('axios');
 
 const API_BASE = 'http://localhost:8080/api';
+// Token organisateur (POST /api/admin/token), requis pour créer une partie
+const ADMIN_TOKEN = process.env.POKER_ADMIN_TOKEN;
 
 class ConservativeBot {
     constructor(name, gameId = null) {
@@ -18,6 +20,8 @@ class ConservativeBot {
                 starting_chips: 1000,
                 small_blind: 10,
                 big_blind: 20
+            }, {
+                headers: { Authorization: `Bearer ${ADMIN_TOKEN}` }
             });
             this.gameId = response.data.game_id;
             console.log(`✅ Partie créée: ${this.gameId}`);
@@ -56,7 +60,7 @@ class ConservativeBot {
 
         try {
             const response = await axios.get(`${API_BASE}/games/${this.gameId}/state`, {
-                params: { player_id: this.playerId }
+                headers: { Authorization: `Bearer ${this.authToken}` }
             });
             return response.data;
         } catch (error) {
@@ -232,7 +236,7 @@ async function main() {
 
     console.log(`\n⏳ En attente d'autres joueurs...`);
     console.log(`   Démarrez la partie avec:`);
-    console.log(`   curl -X POST http://localhost:8080/api/games/${bot.gameId}/start`);
+    console.log(`   curl -X POST -H "Authorization: Bearer $POKER_ADMIN_TOKEN" http://localhost:8080/api/games/${bot.gameId}/start`);
 
     await bot.play();
 }
@@ -32,7 +32,7 @@ public class BotMain {
 
         System.out.println("\n⏳ En attente d'autres joueurs...");
         System.out.println("   Utilisez la commande suivante pour démarrer la partie:");
-        System.out.println("   curl -X POST http://localhost:8080/api/games/" + gameId + "/start");
+        System.out.println("   curl -X POST -H \"Authorization: Bearer $POKER_ADMIN_TOKEN\" http://localhost:8080/api/games/" + gameId + "/start");
         System.out.println();
 
         // Lancer la boucle de jeu
@@ -19,6 +19,7 @@ public class PokerApiClient {
     private final Gson gson;
     private final String gameId;
     private String playerId;
+    private String authToken;
 
     public PokerApiClient(String gameId) {
         this.gameId = gameId;
@@ -47,6 +48,7 @@ public class PokerApiClient {
                 @SuppressWarnings("unchecked")
                 Map<String, Object> result = gson.fromJson(responseBody, Map.class);
                 this.playerId = (String) result.get("player_id");
+                this.authToken = (String) result.get("auth_token");
                 System.out.println("✅ Rejoint la partie en position " + result.get("position"));
                 System.out.println("   Player ID: " + playerId);
                 return true;
@@ -68,13 +70,9 @@ public class PokerApiClient {
             return null;
         }
 
-        HttpUrl url = HttpUrl.parse(API_BASE + "/games/" + gameId + "/state")
-                .newBuilder()
-                .addQueryParameter("player_id", playerId)
-                .build();
-
         Request request = new Request.Builder()
-                .url(url)
+                .url(API_BASE + "/games/" + gameId + "/state")
+                .header("Authorization", "Bearer " + authToken)
                 .get()
                 .build();
 
@@ -105,7 +103,7 @@ public class PokerApiClient {
         }
 
         Map<String, Object> requestBody = new HashMap<>();
-        requestBody.put("player_id", playerId);
+        requestBody.put("auth_token", authToken);
         requestBody.put("action", action);
 
         String json = gson.toJson(requestBody);
@@ -1,9 +1,11 @@
 use crate::card::{Card, Rank, Suit};
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 
 /// Représente un paquet de cartes
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Deck {
     cards: Vec<Card>,
 }
@@ -22,12 +24,32 @@ impl Deck {
         Deck { cards }
     }
 
+    /// Paquet de 52 cartes mélangé à partir d'une graine : même graine, même paquet
+    pub fn with_seed(seed: u64) -> Self {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(seed);
+        deck
+    }
+
+    /// Paquet préparé : les cartes sont distribuées dans l'ordre donné
+    pub fn from_cards(cards: Vec<Card>) -> Self {
+        let mut cards = cards;
+        cards.reverse(); // `deal` prend la dernière carte
+        Deck { cards }
+    }
+
     /// Mélange le paquet (algorithme Fisher-Yates)
     pub fn shuffle(&mut self) {
         let mut rng = thread_rng();
         self.cards.shuffle(&mut rng);
     }
 
+    /// Mélange reproductible du paquet à partir d'une graine
+    pub fn shuffle_with_seed(&mut self, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        self.cards.shuffle(&mut rng);
+    }
+
     /// Distribue une carte du dessus du paquet
     pub fn deal(&mut self) -> Option<Card> {
         self.cards.pop()
@@ -101,6 +123,20 @@ mod tests {
         assert_ne!(cards1, cards2);
     }
 
+    #[test]
+    fn test_seeded_and_stacked_decks() {
+        let deal_all = |mut deck: Deck| -> Vec<Card> { (0..52).map(|_| deck.deal().unwrap()).collect() };
+        assert_eq!(deal_all(Deck::with_seed(42)), deal_all(Deck::with_seed(42)));
+        assert_ne!(deal_all(Deck::with_seed(42)), deal_all(Deck::with_seed(43)));
+
+        let ace = Card::new(Rank::Ace, Suit::Spades);
+        let king = Card::new(Rank::King, Suit::Hearts);
+        let mut stacked = Deck::from_cards(vec![ace, king]);
+        assert_eq!(stacked.deal(), Some(ace));
+        assert_eq!(stacked.deal(), Some(king));
+        assert_eq!(stacked.deal(), None);
+    }
+
     #[test]
     fn test_reset() {
         let mut deck = Deck::new();
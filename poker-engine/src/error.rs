@@ -0,0 +1,27 @@
+use crate::game::PlayerStatus;
+use thiserror::Error;
+
+/// Erreurs renvoyées par le moteur lorsqu'une action est refusée
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum GameError {
+    #[error("Joueur non trouvé")]
+    PlayerNotFound,
+
+    #[error("Ce n'est pas le tour de ce joueur")]
+    NotYourTurn,
+
+    #[error("Le joueur ne peut pas agir (statut: {status:?})")]
+    PlayerNotActive { status: PlayerStatus },
+
+    #[error("Impossible de checker, il faut suivre ou se coucher")]
+    CannotCheck,
+
+    #[error("Raise trop petit. Minimum: {min}")]
+    RaiseTooSmall { min: u32 },
+
+    #[error("Raise trop grand. Maximum: {max}")]
+    RaiseTooLarge { max: u32 },
+
+    #[error("Relance impossible : un tapis incomplet ne rouvre pas l'action")]
+    ActionNotReopened,
+}
@@ -0,0 +1,184 @@
+use crate::card::Card;
+use crate::game::{GamePhase, PlayerId};
+use crate::hand::Hand;
+use serde::{Deserialize, Serialize};
+
+/// Mise forcée postée en début de main
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlindKind {
+    Ante,
+    SmallBlind,
+    BigBlind,
+    Straddle,
+    MissedBigBlind, // Big blind manquée, postée au retour d'une absence
+}
+
+/// Décision d'un joueur pendant un tour d'enchères
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionKind {
+    Fold,
+    Check,
+    Call,
+    Bet,   // Première mise d'un tour d'enchères
+    Raise, // Relance d'une mise existante
+}
+
+/// Joueur servi au début d'une main
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandSeat {
+    pub seat: usize, // Numéro de siège, à partir de 1
+    pub player_id: PlayerId,
+    pub name: String,
+    pub stack: u32, // Tapis avant les mises forcées
+}
+
+/// Événement du journal de la partie
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HandEvent {
+    HandStarted {
+        hand_number: u32,
+        started_at: u64, // Horodatage Unix (secondes)
+        small_blind: u32,
+        big_blind: u32,
+        ante: u32,
+        button: usize, // Siège du bouton
+        seats: Vec<HandSeat>,
+    },
+    BlindPosted {
+        player: PlayerId,
+        blind: BlindKind,
+        amount: u32,
+        all_in: bool,
+    },
+    /// Privé : cartes d'un seul joueur
+    HoleCardsDealt {
+        player: PlayerId,
+        cards: Vec<Card>,
+    },
+    ActionTaken {
+        player: PlayerId,
+        action: ActionKind,
+        amount: u32, // Jetons ajoutés au pot par cette action
+        total: u32,  // Mise du joueur sur ce tour après l'action
+        street: GamePhase,
+        all_in: bool,
+    },
+    BoardDealt {
+        street: GamePhase,
+        cards: Vec<Card>, // Nouvelles cartes communes de ce tour
+    },
+    Showdown {
+        player: PlayerId,
+        hole_cards: Vec<Card>,
+        hand: Hand,
+    },
+    PotAwarded {
+        player: PlayerId,
+        amount: u32,
+        pot: usize, // 0 pour le pot principal, puis les pots secondaires
+    },
+    /// Message de table signalé par le serveur (arrivée, pause, exclusion...)
+    Note {
+        message: String,
+    },
+}
+
+/// Événement numéroté (numéro croissant sur toute la partie)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoggedEvent {
+    pub seq: u64,
+    pub event: HandEvent,
+}
+
+impl HandEvent {
+    /// L'événement ne concerne-t-il qu'un seul joueur (cartes privées) ?
+    pub fn is_private(&self) -> bool {
+        matches!(self, HandEvent::HoleCardsDealt { .. })
+    }
+
+    /// L'événement peut-il être montré à ce joueur (None : spectateur) ?
+    pub fn is_visible_to(&self, viewer: Option<&PlayerId>) -> bool {
+        match self {
+            HandEvent::HoleCardsDealt { player, .. } => viewer == Some(player),
+            _ => true,
+        }
+    }
+
+    /// Texte lisible de l'événement, `name` donnant le nom d'un joueur
+    pub fn describe(&self, name: impl Fn(&PlayerId) -> String) -> String {
+        let cards = |cards: &[Card]| cards.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(" ");
+        let all_in = |all_in: bool| if all_in { " (all-in)" } else { "" };
+
+        match self {
+            HandEvent::HandStarted { hand_number, small_blind, big_blind, .. } => {
+                format!("Hand #{} ({}/{})", hand_number, small_blind, big_blind)
+            }
+            HandEvent::BlindPosted { player, blind, amount, all_in: is_all_in } => {
+                let blind = match blind {
+                    BlindKind::Ante => "the ante",
+                    BlindKind::SmallBlind => "small blind",
+                    BlindKind::BigBlind => "big blind",
+                    BlindKind::Straddle => "straddle",
+                    BlindKind::MissedBigBlind => "missed big blind",
+                };
+                format!("{} posts {} {}{}", name(player), blind, amount, all_in(*is_all_in))
+            }
+            HandEvent::HoleCardsDealt { player, cards: hole_cards } => {
+                format!("{} is dealt [{}]", name(player), cards(hole_cards))
+            }
+            HandEvent::ActionTaken { player, action, amount, total, all_in: is_all_in, .. } => {
+                let action = match action {
+                    ActionKind::Fold => "folds".to_string(),
+                    ActionKind::Check => "checks".to_string(),
+                    ActionKind::Call => format!("calls {}", amount),
+                    ActionKind::Bet => format!("bets {}", amount),
+                    ActionKind::Raise => format!("raises to {}", total),
+                };
+                format!("{} {}{}", name(player), action, all_in(*is_all_in))
+            }
+            HandEvent::BoardDealt { street, cards: board } => format!("{:?}: [{}]", street, cards(board)),
+            HandEvent::Showdown { player, hole_cards, hand } => {
+                format!("{} shows [{}] ({})", name(player), cards(hole_cards), hand.rank)
+            }
+            HandEvent::PotAwarded { player, amount, pot } => match pot {
+                0 => format!("{} wins {} chips", name(player), amount),
+                n => format!("{} wins {} chips (side pot {})", name(player), amount, n),
+            },
+            HandEvent::Note { message } => message.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_and_visibility() {
+        let name = |id: &PlayerId| id.to_uppercase();
+        let raise = HandEvent::ActionTaken {
+            player: "bob".to_string(),
+            action: ActionKind::Raise,
+            amount: 60,
+            total: 80,
+            street: GamePhase::PreFlop,
+            all_in: true,
+        };
+        assert_eq!(raise.describe(name), "BOB raises to 80 (all-in)");
+
+        let dealt = HandEvent::HoleCardsDealt { player: "bob".to_string(), cards: vec![] };
+        assert!(dealt.is_private());
+        assert!(dealt.is_visible_to(Some(&"bob".to_string())));
+        assert!(!dealt.is_visible_to(Some(&"alice".to_string())));
+        assert!(!dealt.is_visible_to(None));
+        assert!(raise.is_visible_to(None));
+
+        let json = serde_json::to_value(LoggedEvent { seq: 3, event: raise }).unwrap();
+        assert_eq!(json["seq"], 3);
+        assert_eq!(json["event"]["type"], "action_taken");
+        assert_eq!(json["event"]["action"], "raise");
+    }
+}
@@ -1,7 +1,14 @@
 use crate::card::Card;
 use crate::deck::Deck;
+use crate::error::GameError;
 use crate::hand::Hand;
+use crate::event::{ActionKind, BlindKind, HandEvent, HandSeat, LoggedEvent};
+use crate::pot::{OddChipRule, Pot};
+use crate::result::{HandResult, PotResult, PotWinner, ShowdownHand};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 
 /// Identifiant unique d'un joueur
 pub type PlayerId = String;
@@ -24,7 +31,12 @@ pub struct Player {
     pub chips: u32,
     pub hole_cards: Vec<Card>,
     pub current_bet: u32,
+    pub total_bet: u32, // Total misé depuis le début de la main (pour les pots secondaires)
     pub status: PlayerStatus,
+    #[serde(default)]
+    pub sitting_out: bool,   // Absent à partir de la prochaine main, jusqu'à son retour
+    #[serde(default)]
+    pub missed_blinds: bool, // La big blind est passée pendant son absence : il la doit à son retour
 }
 
 impl Player {
@@ -35,7 +47,10 @@ impl Player {
             chips,
             hole_cards: Vec::new(),
             current_bet: 0,
+            total_bet: 0,
             status: PlayerStatus::Active,
+            sitting_out: false,
+            missed_blinds: false,
         }
     }
 
@@ -44,6 +59,7 @@ impl Player {
         let actual_bet = amount.min(self.chips);
         self.chips -= actual_bet;
         self.current_bet += actual_bet;
+        self.total_bet += actual_bet;
         
         if self.chips == 0 {
             self.status = PlayerStatus::AllIn;
@@ -52,6 +68,19 @@ impl Player {
         actual_bet
     }
 
+    /// Poster une ante (jetons morts : comptent pour le pot mais pas pour la mise du tour)
+    pub fn post_ante(&mut self, amount: u32) -> u32 {
+        let actual_ante = amount.min(self.chips);
+        self.chips -= actual_ante;
+        self.total_bet += actual_ante;
+
+        if self.chips == 0 {
+            self.status = PlayerStatus::AllIn;
+        }
+
+        actual_ante
+    }
+
     /// Se coucher
     pub fn fold(&mut self) {
         self.status = PlayerStatus::Folded;
@@ -61,11 +90,14 @@ impl Player {
     pub fn reset_for_new_hand(&mut self) {
         self.hole_cards.clear();
         self.current_bet = 0;
-        if self.chips > 0 {
-            self.status = PlayerStatus::Active;
+        self.total_bet = 0;
+        self.status = if self.chips == 0 {
+            PlayerStatus::Eliminated
+        } else if self.sitting_out {
+            PlayerStatus::SittingOut
         } else {
-            self.status = PlayerStatus::Eliminated;
-        }
+            PlayerStatus::Active
+        };
     }
 }
 
@@ -89,6 +121,46 @@ pub enum PlayerAction {
     AllIn,
 }
 
+/// Structure des mises forcées (blinds, antes, straddle)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct BlindsConfig {
+    pub small_blind: u32,
+    pub big_blind: u32,
+    #[serde(default)]
+    pub ante: u32,            // Ante par joueur, ou ante totale de la table si big_blind_ante
+    #[serde(default)]
+    pub big_blind_ante: bool, // La big blind paie l'ante pour toute la table
+    #[serde(default)]
+    pub straddle: bool,       // Straddle UTG obligatoire (2x la big blind, 3 joueurs minimum)
+}
+
+impl BlindsConfig {
+    /// Blinds simples, sans ante ni straddle
+    pub fn new(small_blind: u32, big_blind: u32) -> Self {
+        BlindsConfig {
+            small_blind,
+            big_blind,
+            ..Default::default()
+        }
+    }
+}
+
+/// Traitement des blinds manquées par un joueur absent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MissedBlindsRule {
+    #[default]
+    PostBigBlind, // Si la big blind est passée pendant son absence, il la poste à son retour
+    Ignore,       // Retour immédiat, sans rien devoir
+}
+
+/// Bornes légales d'une relance, en montant "relance à" (mise totale du joueur sur le tour)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaiseLimits {
+    pub min_raise_to: u32,
+    pub max_raise_to: u32, // Tapis du joueur
+}
+
 /// État du jeu
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
@@ -98,26 +170,63 @@ pub struct GameState {
     pub current_bet: u32,
     pub phase: GamePhase,
     pub dealer_position: usize,
+    pub small_blind_position: usize,
+    pub big_blind_position: usize,
     pub current_player: usize,
     pub small_blind: u32,
     pub big_blind: u32,
+    pub ante: u32,
+    pub big_blind_ante: bool,
+    pub straddle: bool,
+    pub straddle_position: Option<usize>, // Joueur ayant posté le straddle dans la main en cours
+    #[serde(default)]
+    pub next_blinds: Option<BlindsConfig>, // Mises forcées à appliquer dès la prochaine main
     pub players_acted: Vec<bool>, // Suit qui a agi dans le tour actuel
+    pub last_raise_size: u32,     // Taille de la dernière relance complète (minimum de la suivante)
+    pub odd_chip_rule: OddChipRule,
+    #[serde(default)]
+    pub missed_blinds_rule: MissedBlindsRule,
+    pub hand_number: u32, // Numéro de la main en cours (0 avant la première)
+    #[serde(default)]
+    pub seed: Option<u64>, // Graine de la partie : mêmes donnes à chaque partie de même graine (None = hasard)
+    #[serde(default)]
+    pub hand_limit: Option<u32>, // Nombre de mains à jouer (None = jusqu'à ce qu'il reste un joueur)
     
     // Champs d'historique pour l'UI
-    pub action_log: Vec<String>,
-    pub last_hand_winner: Option<usize>,
-    pub last_hand_amount: u32,
-    pub last_hand_description: String,
-    pub last_hand_winner_name: Option<String>,
-    pub last_hand_cards: Option<Vec<Card>>,
-
-    #[serde(skip)]
-    deck: Deck,
+    #[serde(default)]
+    pub events: Vec<LoggedEvent>, // Journal numéroté de la main en cours (de la dernière main entre deux mains)
+    pub last_hand_result: Option<HandResult>,
+
+    // Changements de places (changement de table, départ, arrivée) appliqués entre deux mains
+    #[serde(default)]
+    pending_removals: Vec<PlayerId>,
+    #[serde(default)]
+    pending_players: Vec<Player>,
+    #[serde(default)]
+    removed_players: Vec<Player>, // Joueurs retirés, avec leur tapis, en attente de récupération
+    #[serde(default)]
+    pending_chips: Vec<(PlayerId, u32)>, // Jetons achetés pendant une main (recave, complément)
+    #[serde(default)]
+    last_seq: u64, // Numéro du dernier événement des mains précédentes, retirées du journal
+    #[serde(default)]
+    completed_hands: Vec<HandResult>, // Mains terminées, en attente de récupération
+
+    #[serde(default)]
+    deck: Deck, // Conservé avec l'état : une main interrompue reprend avec le même paquet
+    #[serde(default)]
+    hand_seed: Option<u64>, // Graine du paquet de la main en cours (None : paquet préparé)
+    #[serde(default)]
+    next_deck: Option<Deck>, // Paquet préparé pour la prochaine main
 }
 
 impl GameState {
     /// Crée une nouvelle partie
     pub fn new(player_ids: Vec<(PlayerId, String)>, starting_chips: u32, small_blind: u32, big_blind: u32) -> Self {
+        Self::with_blinds(player_ids, starting_chips, BlindsConfig::new(small_blind, big_blind))
+    }
+
+    /// Crée une nouvelle partie avec antes et/ou straddle
+    pub fn with_blinds(player_ids: Vec<(PlayerId, String)>, starting_chips: u32, blinds: BlindsConfig) -> Self {
         let num_players = player_ids.len();
         let players = player_ids
             .into_iter()
@@ -131,24 +240,219 @@ impl GameState {
             current_bet: 0,
             phase: GamePhase::PreFlop,
             dealer_position: 0,
+            small_blind_position: 0,
+            big_blind_position: 0,
             current_player: 0,
-            small_blind,
-            big_blind,
+            small_blind: blinds.small_blind,
+            big_blind: blinds.big_blind,
+            ante: blinds.ante,
+            big_blind_ante: blinds.big_blind_ante,
+            straddle: blinds.straddle,
+            straddle_position: None,
+            next_blinds: None,
             players_acted: vec![false; num_players],
+            last_raise_size: blinds.big_blind,
+            odd_chip_rule: OddChipRule::default(),
+            missed_blinds_rule: MissedBlindsRule::default(),
+            hand_number: 0,
+            seed: None,
+            hand_limit: None,
             deck: Deck::new(),
+            hand_seed: None,
+            next_deck: None,
             
             // Initialisation des champs d'historique
-            action_log: Vec::new(),
-            last_hand_winner: None,
-            last_hand_amount: 0,
-            last_hand_description: String::new(),
-            last_hand_winner_name: None,
-            last_hand_cards: None,
+            events: Vec::new(),
+            last_hand_result: None,
+
+            pending_removals: Vec::new(),
+            pending_players: Vec::new(),
+            removed_players: Vec::new(),
+            pending_chips: Vec::new(),
+            last_seq: 0,
+            completed_hands: Vec::new(),
+        }
+    }
+
+    /// Structure des mises forcées actuelle
+    pub fn blinds(&self) -> BlindsConfig {
+        BlindsConfig {
+            small_blind: self.small_blind,
+            big_blind: self.big_blind,
+            ante: self.ante,
+            big_blind_ante: self.big_blind_ante,
+            straddle: self.straddle,
+        }
+    }
+
+    /// Programmer de nouvelles mises forcées pour la prochaine main (changement de niveau)
+    pub fn schedule_blinds(&mut self, blinds: BlindsConfig) {
+        self.next_blinds = Some(blinds);
+    }
+
+    /// Modifier immédiatement les mises forcées (entre deux mains uniquement)
+    pub fn set_blinds(&mut self, blinds: BlindsConfig) {
+        self.small_blind = blinds.small_blind;
+        self.big_blind = blinds.big_blind;
+        self.ante = blinds.ante;
+        self.big_blind_ante = blinds.big_blind_ante;
+        self.straddle = blinds.straddle;
+    }
+
+    /// Toutes les mains prévues ont-elles été jouées ?
+    pub fn hand_limit_reached(&self) -> bool {
+        self.hand_limit.is_some_and(|limit| self.hand_number >= limit) && !self.is_hand_in_progress()
+    }
+
+    /// Une main est-elle en cours ? (faux avant la première main et quand la table attend des joueurs)
+    pub fn is_hand_in_progress(&self) -> bool {
+        self.hand_number > 0 && self.phase != GamePhase::Showdown
+    }
+
+    /// Asseoir un joueur : immédiatement entre deux mains, sinon dès la fin de la main en cours
+    pub fn add_player(&mut self, player: Player) {
+        if self.is_hand_in_progress() {
+            self.pending_players.push(player);
+        } else {
+            self.players.push(player);
+        }
+    }
+
+    /// Retirer un joueur de la table : immédiatement entre deux mains, sinon dès la fin de la main en cours
+    ///
+    /// Le joueur retiré (et son tapis) est ensuite disponible via `take_removed_players`.
+    pub fn remove_player(&mut self, player_id: &PlayerId) -> Result<(), GameError> {
+        // Arrivée programmée pas encore effective : elle est simplement annulée
+        if let Some(idx) = self.pending_players.iter().position(|p| &p.id == player_id) {
+            let player = self.pending_players.remove(idx);
+            self.removed_players.push(player);
+            return Ok(());
+        }
+
+        if !self.players.iter().any(|p| &p.id == player_id) {
+            return Err(GameError::PlayerNotFound);
+        }
+
+        if !self.is_hand_in_progress() {
+            self.remove_seat(player_id);
+        } else if !self.pending_removals.contains(player_id) {
+            self.pending_removals.push(player_id.clone());
+        }
+        Ok(())
+    }
+
+    /// Nombre de places occupées, en comptant les arrivées et départs programmés
+    pub fn seat_count(&self) -> usize {
+        self.players.len() + self.pending_players.len() - self.pending_removals.len()
+    }
+
+    /// Joueur assis à la table ou en attente de l'être
+    pub fn find_player(&self, player_id: &PlayerId) -> Option<&Player> {
+        self.players.iter().chain(&self.pending_players).find(|p| &p.id == player_id)
+    }
+
+    /// Le joueur est-il assis, ou sur le point de l'être ? (faux s'il quitte la table)
+    pub fn has_player(&self, player_id: &PlayerId) -> bool {
+        self.pending_players.iter().any(|p| &p.id == player_id)
+            || (self.players.iter().any(|p| &p.id == player_id) && !self.pending_removals.contains(player_id))
+    }
+
+    /// Ajouter des jetons au tapis d'un joueur : immédiatement entre deux mains, sinon dès
+    /// la fin de la main en cours (les jetons en jeu ne changent jamais pendant une main)
+    pub fn add_chips(&mut self, player_id: &PlayerId, amount: u32) -> Result<(), GameError> {
+        if !self.has_player(player_id) {
+            return Err(GameError::PlayerNotFound);
+        }
+
+        if self.is_hand_in_progress() && self.players.iter().any(|p| &p.id == player_id) {
+            self.pending_chips.push((player_id.clone(), amount));
+        } else if let Some(player) = self.players.iter_mut().chain(self.pending_players.iter_mut()).find(|p| &p.id == player_id) {
+            player.chips += amount;
         }
+        Ok(())
+    }
+
+    /// Jetons achetés par un joueur, pas encore ajoutés à son tapis
+    pub fn pending_chips(&self, player_id: &PlayerId) -> u32 {
+        self.pending_chips.iter()
+            .filter(|(id, _)| id == player_id)
+            .map(|(_, amount)| amount)
+            .sum()
+    }
+
+    /// Passer un joueur absent : il n'est plus servi ni ne paie les blinds
+    ///
+    /// En pleine main, il termine la main en cours et s'absente à partir de la suivante.
+    pub fn sit_out(&mut self, player_id: &PlayerId) -> Result<(), GameError> {
+        let in_progress = self.is_hand_in_progress();
+        let player = self.players.iter_mut()
+            .find(|p| &p.id == player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+
+        player.sitting_out = true;
+        if !in_progress && player.status == PlayerStatus::Active {
+            player.status = PlayerStatus::SittingOut;
+        }
+        Ok(())
+    }
+
+    /// Retour d'un joueur absent : servi dès la prochaine main
+    pub fn sit_in(&mut self, player_id: &PlayerId) -> Result<(), GameError> {
+        let in_progress = self.is_hand_in_progress();
+        let player = self.players.iter_mut()
+            .find(|p| &p.id == player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+
+        player.sitting_out = false;
+        if !in_progress && player.status == PlayerStatus::SittingOut && player.chips > 0 {
+            player.status = PlayerStatus::Active;
+        }
+        Ok(())
+    }
+
+    /// Récupérer les joueurs retirés de la table depuis le dernier appel
+    pub fn take_removed_players(&mut self) -> Vec<Player> {
+        std::mem::take(&mut self.removed_players)
+    }
+
+    /// Retirer un siège en conservant le bouton à sa place logique
+    fn remove_seat(&mut self, player_id: &PlayerId) {
+        let Some(idx) = self.players.iter().position(|p| &p.id == player_id) else {
+            return;
+        };
+        let player = self.players.remove(idx);
+        self.removed_players.push(player);
+
+        if self.players.is_empty() {
+            self.dealer_position = 0;
+        } else if idx <= self.dealer_position {
+            // Le bouton recule d'un siège : le joueur suivant le recevra à la prochaine main
+            self.dealer_position = (self.dealer_position + self.players.len() - 1) % self.players.len();
+        }
+        self.current_player = self.current_player.min(self.players.len().saturating_sub(1));
+    }
+
+    /// Appliquer les jetons achetés, départs et arrivées programmés pendant la main précédente
+    fn apply_seat_changes(&mut self) {
+        for (player_id, amount) in std::mem::take(&mut self.pending_chips) {
+            if let Some(player) = self.players.iter_mut().find(|p| p.id == player_id) {
+                player.chips += amount;
+            }
+        }
+        for player_id in std::mem::take(&mut self.pending_removals) {
+            self.remove_seat(&player_id);
+        }
+        self.players.append(&mut self.pending_players);
     }
 
     /// Démarre une nouvelle main
+    ///
+    /// Si moins de deux joueurs ont des jetons, aucune main ne démarre : la table reste
+    /// en attente (phase Showdown) jusqu'à l'arrivée d'un adversaire. Il en va de même
+    /// une fois `hand_limit` mains jouées.
     pub fn start_new_hand(&mut self) {
+        self.apply_seat_changes();
+
         // Réinitialiser les joueurs
         for player in &mut self.players {
             player.reset_for_new_hand();
@@ -158,29 +462,81 @@ impl GameState {
         self.community_cards.clear();
         self.pot = 0;
         self.current_bet = 0;
+
+        let limit_reached = self.hand_limit.is_some_and(|limit| self.hand_number >= limit);
+        if limit_reached || self.players.iter().filter(|p| p.status == PlayerStatus::Active).count() < 2 {
+            self.phase = GamePhase::Showdown;
+            self.straddle_position = None;
+            return;
+        }
+
         self.phase = GamePhase::PreFlop;
+        self.hand_number += 1;
+        // Les mains précédentes sont conservées dans leur résultat : le journal repart de zéro,
+        // la numérotation continue
+        self.last_seq = self.events.last().map_or(self.last_seq, |e| e.seq);
+        self.events.clear();
+
+        // Changement de niveau de blinds programmé
+        if let Some(blinds) = self.next_blinds.take() {
+            self.set_blinds(blinds);
+        }
 
         // Déplacer le bouton du dealer vers le prochain joueur ACTIF
         self.dealer_position = self.get_next_active_player(self.dealer_position);
+        self.log(HandEvent::HandStarted {
+            hand_number: self.hand_number,
+            started_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            small_blind: self.small_blind,
+            big_blind: self.big_blind,
+            ante: self.ante,
+            button: self.dealer_position + 1,
+            seats: self.players.iter().enumerate()
+                .filter(|(_, p)| p.status == PlayerStatus::Active)
+                .map(|(idx, p)| HandSeat {
+                    seat: idx + 1,
+                    player_id: p.id.clone(),
+                    name: p.name.clone(),
+                    stack: p.chips,
+                })
+                .collect(),
+        });
         
         // Reset acted status
         self.players_acted = vec![false; self.players.len()];
+        self.last_raise_size = self.big_blind;
 
-        // Nouveau paquet mélangé
-        self.deck = Deck::new();
-        self.deck.shuffle();
+        // Nouveau paquet : préparé, ou mélangé avec une graine notée dans le résultat de la main
+        match self.next_deck.take() {
+            Some(deck) => {
+                self.deck = deck;
+                self.hand_seed = None;
+            }
+            None => {
+                let seed = self.seed.map_or_else(rand::random, |seed| hand_seed(seed, self.hand_number));
+                self.deck = Deck::with_seed(seed);
+                self.hand_seed = Some(seed);
+            }
+        }
 
-        // Poster les blinds
+        // Poster les blinds (positions calculées avant que des joueurs passent à tapis)
+        let (sb_pos, bb_pos) = self.blind_positions();
+        if self.hand_number > 1 && self.missed_blinds_rule == MissedBlindsRule::PostBigBlind {
+            self.mark_missed_blinds(self.big_blind_position, bb_pos);
+        }
+        self.small_blind_position = sb_pos;
+        self.big_blind_position = bb_pos;
         self.post_blinds();
 
         // Distribuer les cartes
         self.deal_hole_cards();
 
-        // Le premier joueur après la big blind commence (UTG)
-        // Dealer -> SB -> BB -> UTG
-        let sb_pos = self.get_next_active_player(self.dealer_position);
-        let bb_pos = self.get_next_active_player(sb_pos);
-        self.current_player = self.get_next_active_player(bb_pos);
+        // Le premier joueur après la big blind (ou le straddle) commence
+        // Dealer -> SB -> BB -> UTG ; en heads-up, c'est le bouton (SB) qui parle en premier
+        self.current_player = self.get_next_active_player(self.straddle_position.unwrap_or(bb_pos));
 
         // Si tout le monde est déjà All-In (ou éliminé/seul), avancer
         let active_count = self.players.iter().filter(|p| p.status == PlayerStatus::Active).count();
@@ -190,125 +546,252 @@ impl GameState {
         }
     }
 
-    /// Poster les blinds
-    fn post_blinds(&mut self) {
-        // Trouver SB et BB parmi les joueurs ACTIFS uniquement
-        let sb_pos = self.get_next_active_player(self.dealer_position);
+    /// Positions de la small blind et de la big blind parmi les joueurs ACTIFS
+    ///
+    /// En heads-up (2 joueurs actifs, y compris quand une table se réduit en cours de partie),
+    /// le bouton poste la small blind : il parle en premier préflop puis en dernier après le flop.
+    fn blind_positions(&self) -> (usize, usize) {
+        let active_count = self.players.iter().filter(|p| p.status == PlayerStatus::Active).count();
+        let sb_pos = if active_count == 2 {
+            self.dealer_position
+        } else {
+            self.get_next_active_player(self.dealer_position)
+        };
         let bb_pos = self.get_next_active_player(sb_pos);
+        (sb_pos, bb_pos)
+    }
+
+    /// Noter les joueurs absents devant lesquels la big blind est passée (sièges de
+    /// `previous_bb` exclu à `bb_pos` inclus)
+    fn mark_missed_blinds(&mut self, previous_bb: usize, bb_pos: usize) {
+        let num_players = self.players.len();
+        let mut idx = previous_bb;
+        for _ in 0..num_players {
+            idx = (idx + 1) % num_players;
+            if self.players[idx].status == PlayerStatus::SittingOut {
+                self.players[idx].missed_blinds = true;
+            }
+            if idx == bb_pos {
+                break;
+            }
+        }
+    }
+
+    /// Poster les antes, les blinds et l'éventuel straddle
+    fn post_blinds(&mut self) {
+        let active_count = self.players.iter().filter(|p| p.status == PlayerStatus::Active).count();
 
-        // Si HEADS-UP (2 joueurs actifs), le Dealer est SB, l'autre est BB
-        // La logique standard next(dealer) -> SB marche si on considère que le dealer joue !
-        // En Heads-up: Dealer = SB, Autre = BB.
-        // Ma logique actuelle: Dealer -> next -> SB.
-        // Si Dealer est actif, next(dealer) = l'autre joueur.
-        // Donc en HU normal: Dealer(SB) -> Opponent(BB).
-        // Mais ma fonction: next(dealer) saute le dealer s'il est actif ? 
-        // get_next_active_player fait (idx+1)%len. 
-        // Si Dealer est P1. next(P1) = P2.
-        // Donc SB = P2. Ce qui est l'inverse du HU standard (où Dealer = SB).
-        // TODO: Ajustement pour Heads-Up plus tard si besoin, restons sur la logique standard pour l'instant.
-
-        let sb_amount = self.players[sb_pos].bet(self.small_blind);
+        // Antes classiques : prioritaires sur les blinds si un joueur est court
+        if self.ante > 0 && !self.big_blind_ante {
+            for idx in 0..self.players.len() {
+                if self.players[idx].status == PlayerStatus::Active {
+                    let ante = self.players[idx].post_ante(self.ante);
+                    self.pot += ante;
+                    self.record_blind(idx, BlindKind::Ante, ante);
+                }
+            }
+        }
+
+        let sb_amount = self.players[self.small_blind_position].bet(self.small_blind);
         self.pot += sb_amount;
+        self.record_blind(self.small_blind_position, BlindKind::SmallBlind, sb_amount);
 
-        let bb_amount = self.players[bb_pos].bet(self.big_blind);
+        let bb_amount = self.players[self.big_blind_position].bet(self.big_blind);
         self.pot += bb_amount;
         self.current_bet = self.big_blind;
+        self.record_blind(self.big_blind_position, BlindKind::BigBlind, bb_amount);
+
+        // Big blind ante : la blind passe avant l'ante si la BB est courte
+        if self.ante > 0 && self.big_blind_ante {
+            let ante = self.players[self.big_blind_position].post_ante(self.ante);
+            self.pot += ante;
+            self.record_blind(self.big_blind_position, BlindKind::Ante, ante);
+        }
+
+        // Straddle UTG : blind volontaire de 2x BB, le straddler garde la parole en dernier
+        self.straddle_position = None;
+        if self.straddle && active_count >= 3 {
+            let straddle_pos = self.get_next_active_player(self.big_blind_position);
+            let straddle_amount = self.big_blind * 2;
+            let posted = self.players[straddle_pos].bet(straddle_amount);
+            self.pot += posted;
+            self.current_bet = straddle_amount;
+            self.last_raise_size = straddle_amount;
+            self.straddle_position = Some(straddle_pos);
+            self.record_blind(straddle_pos, BlindKind::Straddle, posted);
+        }
+
+        // Retour après une absence : big blind manquée postée (mise vivante, le joueur garde la parole)
+        for idx in 0..self.players.len() {
+            let player = &mut self.players[idx];
+            if player.status != PlayerStatus::Active || !player.missed_blinds {
+                continue;
+            }
+            player.missed_blinds = false;
+            if player.current_bet < self.big_blind {
+                let posted = player.bet(self.big_blind - player.current_bet);
+                self.pot += posted;
+                self.record_blind(idx, BlindKind::MissedBigBlind, posted);
+            }
+        }
+    }
+
+    /// Imposer le paquet de la prochaine main (reproduction d'un bug, tests)
+    pub fn stack_next_deck(&mut self, deck: Deck) {
+        self.next_deck = Some(deck);
+    }
+
+    /// Récupérer les mains terminées depuis le dernier appel
+    ///
+    /// Une seule action peut en terminer plusieurs : quand tous les joueurs sont à tapis
+    /// dès les blinds ou l'ante, la main suivante est jouée jusqu'au bout dans la foulée.
+    pub fn take_completed_hands(&mut self) -> Vec<HandResult> {
+        std::mem::take(&mut self.completed_hands)
+    }
+
+    /// Ajouter un événement au journal, avec le numéro suivant
+    fn log(&mut self, event: HandEvent) {
+        let seq = self.events.last().map_or(self.last_seq, |e| e.seq) + 1;
+        self.events.push(LoggedEvent { seq, event });
+    }
+
+    /// Ajouter un message de table au journal (arrivée, pause, exclusion...)
+    pub fn note(&mut self, message: impl Into<String>) {
+        self.log(HandEvent::Note { message: message.into() });
+    }
+
+    /// Événements de la main en cours (de la dernière main entre deux mains)
+    pub fn hand_events(&self) -> &[LoggedEvent] {
+        &self.events
+    }
+
+    /// Noter une mise forcée dans le journal
+    fn record_blind(&mut self, player_idx: usize, blind: BlindKind, amount: u32) {
+        let player = &self.players[player_idx];
+        self.log(HandEvent::BlindPosted {
+            player: player.id.clone(),
+            blind,
+            amount,
+            all_in: player.status == PlayerStatus::AllIn,
+        });
+    }
+
+    /// Noter la décision d'un joueur dans le journal
+    fn record_action(&mut self, player_idx: usize, action: ActionKind, amount: u32) {
+        let player = &self.players[player_idx];
+        self.log(HandEvent::ActionTaken {
+            player: player.id.clone(),
+            action,
+            amount,
+            total: player.current_bet,
+            street: self.phase,
+            all_in: player.status == PlayerStatus::AllIn,
+        });
     }
 
     /// Distribuer les cartes privées
     fn deal_hole_cards(&mut self) {
         for _ in 0..2 {
             for player in &mut self.players {
-                // Ne distribuer qu'aux joueurs actifs
-                if player.status == PlayerStatus::Active {
+                // Joueurs de la main, y compris ceux mis à tapis par une blind ou l'ante
+                if matches!(player.status, PlayerStatus::Active | PlayerStatus::AllIn) {
                     if let Some(card) = self.deck.deal() {
                         player.hole_cards.push(card);
                     }
                 }
             }
         }
+        for idx in 0..self.players.len() {
+            let player = &self.players[idx];
+            if !player.hole_cards.is_empty() {
+                let event = HandEvent::HoleCardsDealt { player: player.id.clone(), cards: player.hole_cards.clone() };
+                self.log(event);
+            }
+        }
     }
 
     /// Exécuter une action de joueur
-    pub fn execute_action(&mut self, player_id: &PlayerId, action: PlayerAction) -> Result<(), String> {
+    pub fn execute_action(&mut self, player_id: &PlayerId, action: PlayerAction) -> Result<(), GameError> {
         let player_idx = self.players
             .iter()
             .position(|p| &p.id == player_id)
-            .ok_or("Joueur non trouvé")?;
+            .ok_or(GameError::PlayerNotFound)?;
 
         if player_idx != self.current_player {
-            return Err("Ce n'est pas le tour de ce joueur".to_string());
+            return Err(GameError::NotYourTurn);
         }
 
+        let can_raise = self.can_raise(player_idx);
         let player = &mut self.players[player_idx];
         
         // CORRECTION: Vérifier que le joueur peut agir
         if player.status != PlayerStatus::Active {
-            return Err(format!("Le joueur ne peut pas agir (statut: {:?})", player.status));
+            return Err(GameError::PlayerNotActive { status: player.status });
         }
 
-        let player_name = player.name.clone();
+        // Première mise du tour ou relance d'une mise existante
+        let aggression = if self.current_bet == 0 { ActionKind::Bet } else { ActionKind::Raise };
 
-        match action {
+        let (kind, amount) = match action {
             PlayerAction::Fold => {
                 player.fold();
                 self.players_acted[player_idx] = true;
-                self.action_log.push(format!("{} folds", player_name));
+                (ActionKind::Fold, 0)
             }
             PlayerAction::Check => {
                 if player.current_bet < self.current_bet {
-                    return Err("Impossible de checker, il faut suivre ou se coucher".to_string());
+                    return Err(GameError::CannotCheck);
                 }
                 self.players_acted[player_idx] = true;
-                self.action_log.push(format!("{} checks", player_name));
+                (ActionKind::Check, 0)
             }
             PlayerAction::Call => {
                 let call_amount = self.current_bet - player.current_bet;
                 let actual_bet = player.bet(call_amount);
                 self.pot += actual_bet;
                 self.players_acted[player_idx] = true;
-                self.action_log.push(format!("{} calls {}", player_name, actual_bet));
+                (ActionKind::Call, actual_bet)
             }
             PlayerAction::Raise(amount) => {
-                // CORRECTION: Vérifier le montant minimum de raise
-                let min_raise = self.big_blind.max(self.current_bet);
-                if amount < min_raise {
-                    return Err(format!(
-                        "Raise trop petit. Minimum: {} (big blind: {}, current bet: {})",
-                        min_raise, self.big_blind, self.current_bet
-                    ));
+                // Le montant est l'augmentation par rapport à la mise courante
+                if !can_raise {
+                    return Err(GameError::ActionNotReopened);
                 }
-                
-                let total_bet = self.current_bet + amount;
                 let to_call = self.current_bet - player.current_bet;
+                let max_raise = player.chips.saturating_sub(to_call);
+                if amount > max_raise {
+                    return Err(GameError::RaiseTooLarge { max: max_raise });
+                }
+                // Une relance inférieure au minimum n'est permise que pour un tapis
+                if amount < self.last_raise_size && amount < max_raise {
+                    return Err(GameError::RaiseTooSmall { min: self.last_raise_size });
+                }
+                
                 let actual_bet = player.bet(to_call + amount);
                 self.pot += actual_bet;
-                self.current_bet = total_bet;
-                
-                // Reset acted for everyone else because of the raise
-                for i in 0..self.players_acted.len() {
-                    self.players_acted[i] = false;
-                }
+                let total_bet = player.current_bet;
+                self.apply_raise(total_bet);
                 self.players_acted[player_idx] = true;
-                self.action_log.push(format!("{} raises to {}", player_name, total_bet));
+                (aggression, actual_bet)
             }
             PlayerAction::AllIn => {
                 let all_in_amount = player.chips;
+                if player.current_bet + all_in_amount > self.current_bet && !can_raise {
+                    return Err(GameError::ActionNotReopened);
+                }
                 let actual_bet = player.bet(all_in_amount);
                 self.pot += actual_bet;
                 
-                if player.current_bet > self.current_bet {
-                    self.current_bet = player.current_bet;
-                    // Reset acted if raise
-                    for i in 0..self.players_acted.len() {
-                        self.players_acted[i] = false;
-                    }
+                let raised = player.current_bet > self.current_bet;
+                if raised {
+                    let total_bet = player.current_bet;
+                    self.apply_raise(total_bet);
                 }
                 self.players_acted[player_idx] = true;
-                self.action_log.push(format!("{} goes all-in with {}", player_name, all_in_amount));
+                (if raised { aggression } else { ActionKind::Call }, actual_bet)
             }
-        }
+        };
+        self.record_action(player_idx, kind, amount);
 
         // CORRECTION CRITIQUE: Vérifier si le tour est terminé AVANT de chercher le joueur suivant
         // Cela évite de bloquer quand tous sont foldés/all-in
@@ -327,6 +810,81 @@ impl GameState {
         Ok(())
     }
 
+    /// Coucher un joueur, même hors de son tour (exclusion, départ de la table)
+    ///
+    /// Le joueur qui a la parole se couche normalement. Un autre joueur est couché sans
+    /// changer l'ordre de parole ; la main se termine s'il ne reste qu'un joueur en jeu.
+    /// Sans effet hors d'une main ou si le joueur n'a plus de décision à prendre (déjà
+    /// couché ou à tapis).
+    pub fn force_fold(&mut self, player_id: &PlayerId) -> Result<(), GameError> {
+        let player_idx = self.players
+            .iter()
+            .position(|p| &p.id == player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+
+        if !self.is_hand_in_progress() || self.players[player_idx].status != PlayerStatus::Active {
+            return Ok(());
+        }
+
+        if player_idx == self.current_player {
+            return self.execute_action(player_id, PlayerAction::Fold);
+        }
+
+        let player = &mut self.players[player_idx];
+        player.fold();
+        self.players_acted[player_idx] = true;
+        self.record_action(player_idx, ActionKind::Fold, 0);
+
+        if self.check_sole_survivor() {
+            self.end_hand_early();
+        } else if self.is_betting_round_complete() {
+            self.advance_phase();
+        }
+        Ok(())
+    }
+
+    /// Enregistre une relance à `raise_to`
+    ///
+    /// Une relance complète (au moins la taille de la précédente) devient le nouveau minimum et
+    /// rouvre l'action pour tous. Un tapis incomplet augmente la mise à suivre sans rouvrir
+    /// l'action : les joueurs ayant déjà agi ne pourront que suivre ou se coucher.
+    fn apply_raise(&mut self, raise_to: u32) {
+        let raise_size = raise_to - self.current_bet;
+        self.current_bet = raise_to;
+
+        if raise_size >= self.last_raise_size {
+            self.last_raise_size = raise_size;
+            self.players_acted.iter_mut().for_each(|acted| *acted = false);
+        }
+    }
+
+    /// Le joueur peut-il relancer ? Non s'il a déjà agi depuis la dernière relance complète
+    /// (il fait alors face à un tapis incomplet qui n'a pas rouvert l'action)
+    fn can_raise(&self, player_idx: usize) -> bool {
+        !self.players_acted.get(player_idx).copied().unwrap_or(false)
+    }
+
+    /// Bornes de relance du joueur courant (None s'il ne peut pas relancer)
+    pub fn raise_limits(&self) -> Option<RaiseLimits> {
+        let player = self.players.get(self.current_player)?;
+        if self.phase == GamePhase::Showdown
+            || player.status != PlayerStatus::Active
+            || !self.can_raise(self.current_player)
+        {
+            return None;
+        }
+
+        let max_raise_to = player.current_bet + player.chips;
+        if max_raise_to <= self.current_bet {
+            return None; // Ne peut que suivre (éventuellement à tapis)
+        }
+
+        Some(RaiseLimits {
+            min_raise_to: (self.current_bet + self.last_raise_size).min(max_raise_to),
+            max_raise_to,
+        })
+    }
+
     /// Passer au joueur suivant
     fn advance_to_next_player(&mut self) {
         let starting_player = self.current_player;
@@ -350,11 +908,9 @@ impl GameState {
             }
             
             // Si on a fait un tour complet, vérifier si le tour de mise est terminé
-            if self.current_player == starting_player {
-                if self.is_betting_round_complete() {
-                    self.advance_phase();
-                    return;
-                }
+            if self.current_player == starting_player && self.is_betting_round_complete() {
+                self.advance_phase();
+                return;
             }
 
             // CORRECTION: Si le joueur est actif, c'est son tour
@@ -373,11 +929,17 @@ impl GameState {
             .map(|(i, _)| i)
             .collect();
 
-        // S'il ne reste qu'un joueur (ou 0), le tour est fini
-        if active_players.len() <= 1 {
+        // Plus personne ne peut miser : le tour est fini
+        if active_players.is_empty() {
             return true;
         }
 
+        // Un seul joueur peut encore miser (les autres sont à tapis ou couchés) : le tour
+        // n'est fini qu'une fois la plus haute mise égalée ou s'il n'y a rien à suivre
+        if let [last] = active_players[..] {
+            return self.players[last].current_bet >= self.current_bet;
+        }
+
         // Vérifier si tous les joueurs actifs ont la même mise
         let stakes_equal = active_players
             .iter()
@@ -393,11 +955,13 @@ impl GameState {
 
     /// Avancer à la phase suivante
     fn advance_phase(&mut self) {
-        // Réinitialiser les mises des joueurs
+        // Réinitialiser les mises des joueurs et le tour d'enchères
         for player in &mut self.players {
             player.current_bet = 0;
         }
         self.current_bet = 0;
+        self.last_raise_size = self.big_blind;
+        self.players_acted = vec![false; self.players.len()];
 
         match self.phase {
             GamePhase::PreFlop => {
@@ -448,6 +1012,7 @@ impl GameState {
                 self.community_cards.push(card);
             }
         }
+        self.record_board(3);
     }
 
     /// Distribuer le turn (4ème carte commune)
@@ -456,6 +1021,7 @@ impl GameState {
         if let Some(card) = self.deck.deal() {
             self.community_cards.push(card);
         }
+        self.record_board(1);
     }
 
     /// Distribuer la river (5ème carte commune)
@@ -464,31 +1030,107 @@ impl GameState {
         if let Some(card) = self.deck.deal() {
             self.community_cards.push(card);
         }
+        self.record_board(1);
+    }
+
+    /// Noter dans le journal les `count` dernières cartes communes distribuées
+    fn record_board(&mut self, count: usize) {
+        let start = self.community_cards.len().saturating_sub(count);
+        let cards = self.community_cards[start..].to_vec();
+        self.log(HandEvent::BoardDealt { street: self.phase, cards });
+    }
+
+    /// Pot principal et pots secondaires de la main en cours
+    pub fn pots(&self) -> Vec<Pot> {
+        Pot::build(&self.players)
     }
 
     /// Abattage et détermination du gagnant
     fn showdown(&mut self) {
-        let mut player_hands: Vec<(usize, Hand)> = Vec::new();
+        self.settle_pots();
+        
+        // CORRECTION: Démarrer automatiquement la prochaine main
+        println!("🎴 SHOWDOWN terminé - Démarrage nouvelle main");
+        self.start_new_hand();
+    }
+
+    /// Distribue chaque pot aux meilleures mains parmi les joueurs éligibles
+    /// (partage à égalité, jetons indivisibles selon `odd_chip_rule`)
+    fn settle_pots(&mut self) {
+        let pots = self.pots();
 
-        for (idx, player) in self.players.iter().enumerate() {
-            if player.status != PlayerStatus::Folded {
+        // Toutes les mains encore en lice sont dévoilées
+        let revealed: Vec<(usize, ShowdownHand)> = self.players
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| pots.iter().any(|pot| pot.eligible_players.contains(idx)))
+            .map(|(idx, player)| {
                 let mut all_cards = player.hole_cards.clone();
                 all_cards.extend(&self.community_cards);
-                
-                let hand = Hand::evaluate(&all_cards);
-                player_hands.push((idx, hand));
-            }
+                let shown = ShowdownHand {
+                    player_id: player.id.clone(),
+                    hole_cards: player.hole_cards.clone(),
+                    hand: Hand::evaluate(&all_cards),
+                };
+                (idx, shown)
+            })
+            .collect();
+        for (_, shown) in &revealed {
+            self.log(HandEvent::Showdown {
+                player: shown.player_id.clone(),
+                hole_cards: shown.hole_cards.clone(),
+                hand: shown.hand.clone(),
+            });
         }
 
-        // Trouver le meilleur main
-        if let Some((winner_idx, _)) = player_hands.iter().max_by(|(_, h1), (_, h2)| h1.cmp(h2)) {
-            self.players[*winner_idx].chips += self.pot;
-            self.pot = 0;
+        let mut pot_results = Vec::new();
+        for (pot_idx, pot) in pots.into_iter().enumerate() {
+            let contenders: Vec<&(usize, ShowdownHand)> = revealed
+                .iter()
+                .filter(|(idx, _)| pot.eligible_players.contains(idx))
+                .collect();
+            let Some(best) = contenders.iter().map(|(_, shown)| &shown.hand).max() else {
+                continue;
+            };
+            let winners: Vec<usize> = contenders
+                .iter()
+                .filter(|(_, shown)| shown.hand.cmp(best) == Ordering::Equal) // Même rang et mêmes kickers, couleurs ignorées
+                .map(|(idx, _)| *idx)
+                .collect();
+
+            let shares = self.odd_chip_rule.split(pot.amount, &winners, self.dealer_position, self.players.len());
+            for &(idx, amount) in &shares {
+                self.players[idx].chips += amount;
+                let player = self.players[idx].id.clone();
+                self.log(HandEvent::PotAwarded { player, amount, pot: pot_idx });
+            }
+
+            pot_results.push(PotResult {
+                amount: pot.amount,
+                eligible_players: pot.eligible_players.iter().map(|&idx| self.players[idx].id.clone()).collect(),
+                winners: shares
+                    .into_iter()
+                    .map(|(idx, amount)| PotWinner { player_id: self.players[idx].id.clone(), amount })
+                    .collect(),
+            });
         }
-        
-        // CORRECTION: Démarrer automatiquement la prochaine main
-        println!("🎴 SHOWDOWN terminé - Démarrage nouvelle main");
-        self.start_new_hand();
+        self.pot = 0;
+
+        self.record_hand_result(pot_results, revealed.into_iter().map(|(_, shown)| shown).collect());
+    }
+
+    /// Enregistre le résultat de la main terminée (pour le serveur, l'UI et les bots)
+    fn record_hand_result(&mut self, pots: Vec<PotResult>, revealed_hands: Vec<ShowdownHand>) {
+        let result = HandResult {
+            hand_number: self.hand_number,
+            board: self.community_cards.clone(),
+            pots,
+            revealed_hands,
+            events: self.hand_events().to_vec(),
+            seed: self.hand_seed,
+        };
+        self.completed_hands.push(result.clone());
+        self.last_hand_result = Some(result);
     }
 
     /// Vérifie s'il ne reste qu'un seul joueur survivant (tous les autres foldés)
@@ -525,13 +1167,15 @@ impl GameState {
             // Donner le pot
             self.players[winner_idx].chips += self.pot;
             
-            // Marquer le gagnant dans l'historique (pour le log UI)
-            self.action_log.push(format!("{} wins {} chips (opponent folded)", winner_name, self.pot));
-            self.last_hand_winner = Some(winner_idx);
-            self.last_hand_amount = self.pot;
-            self.last_hand_description = "Adversaire couché".to_string();
-            self.last_hand_winner_name = Some(winner_name.clone());
-            self.last_hand_cards = Some(vec![]); // Pas de cartes à montrer
+            // Marquer le gagnant dans l'historique (pas de cartes à montrer)
+            let winner_id = self.players[winner_idx].id.clone();
+            self.log(HandEvent::PotAwarded { player: winner_id.clone(), amount: self.pot, pot: 0 });
+            let pot = PotResult {
+                amount: self.pot,
+                eligible_players: vec![winner_id.clone()],
+                winners: vec![PotWinner { player_id: winner_id, amount: self.pot }],
+            };
+            self.record_hand_result(vec![pot], vec![]);
             
             self.pot = 0;
             
@@ -564,21 +1208,20 @@ impl GameState {
 
         if to_call == 0 {
             actions.push(PlayerAction::Check);
-            // CORRECTION: Raise minimum = big blind
-            if player.chips > self.big_blind {
-                actions.push(PlayerAction::Raise(self.big_blind));
-            }
         } else if player.chips >= to_call {
             actions.push(PlayerAction::Call);
-            // CORRECTION: Raise minimum = soit la big blind, soit le montant de la dernière raise
-            let min_raise = self.big_blind.max(self.current_bet);
-            if player.chips > to_call + min_raise {
-                actions.push(PlayerAction::Raise(min_raise));
+        }
+
+        // Relance minimum = taille de la dernière relance complète (exprimée en augmentation)
+        let limits = self.raise_limits();
+        if let Some(limits) = limits {
+            if limits.min_raise_to < limits.max_raise_to {
+                actions.push(PlayerAction::Raise(limits.min_raise_to - self.current_bet));
             }
         }
 
-        // All-in toujours disponible si on a des jetons
-        if player.chips > 0 {
+        // All-in possible pour suivre, ou pour relancer si l'action est ouverte
+        if player.chips > 0 && (player.chips <= to_call || limits.is_some()) {
             actions.push(PlayerAction::AllIn);
         }
 
@@ -586,9 +1229,19 @@ impl GameState {
     }
 }
 
+/// Graine du paquet d'une main, tirée de la graine de la partie
+///
+/// Le tirage n'est pas réversible, mais une graine de partie peu aléatoire (42, une date...)
+/// se retrouve par force brute à partir de la graine d'une seule main, et avec elle les
+/// paquets des mains suivantes : la graine d'une main ne doit pas être publiée en cours de partie.
+fn hand_seed(game_seed: u64, hand_number: u32) -> u64 {
+    StdRng::seed_from_u64(game_seed.wrapping_add(u64::from(hand_number))).gen()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::card::{Rank, Suit};
 
     #[test]
     fn test_new_game() {
@@ -683,4 +1336,694 @@ mod tests {
         assert_ne!(game.current_player, 2, "Game blocked: Turn assigned to folded player");
         assert_eq!(game.current_player, 0, "Turn should skip folded player (Charlie) and go to Alice");
     }
+
+    #[test]
+    fn test_heads_up_button_posts_small_blind() {
+        let players = vec![
+            ("p1".to_string(), "Alice".to_string()),
+            ("p2".to_string(), "Bob".to_string()),
+        ];
+        let mut game = GameState::new(players, 1000, 10, 20);
+        game.start_new_hand();
+
+        // Bouton = Bob : il poste la small blind et parle en premier préflop
+        assert_eq!(game.dealer_position, 1);
+        assert_eq!(game.small_blind_position, 1);
+        assert_eq!(game.big_blind_position, 0);
+        assert_eq!(game.players[1].current_bet, 10);
+        assert_eq!(game.players[0].current_bet, 20);
+        assert_eq!(game.current_player, 1);
+
+        game.execute_action(&"p2".to_string(), PlayerAction::Call).unwrap();
+        game.execute_action(&"p1".to_string(), PlayerAction::Check).unwrap();
+
+        // Après le flop, le bouton parle en dernier
+        assert_eq!(game.phase, GamePhase::Flop);
+        assert_eq!(game.current_player, 0);
+    }
+
+    #[test]
+    fn test_big_blind_all_in_from_the_blind_is_dealt_in() {
+        let mut game = GameState::new(vec![
+            ("p1".to_string(), "Alice".to_string()),
+            ("p2".to_string(), "Bob".to_string()),
+        ], 1000, 10, 20);
+        game.players[0].chips = 15; // Alice, big blind, ne peut pas poster la blind entière
+        let card = |rank, suit| Card::new(rank, suit);
+        game.stack_next_deck(Deck::from_cards(vec![
+            card(Rank::Ace, Suit::Spades), card(Rank::Seven, Suit::Clubs),
+            card(Rank::Ace, Suit::Hearts), card(Rank::Two, Suit::Diamonds),
+            card(Rank::Five, Suit::Clubs), // Brûlée
+            card(Rank::King, Suit::Diamonds), card(Rank::Nine, Suit::Clubs), card(Rank::Four, Suit::Hearts),
+            card(Rank::Six, Suit::Diamonds), // Brûlée
+            card(Rank::Three, Suit::Spades),
+            card(Rank::Eight, Suit::Hearts), // Brûlée
+            card(Rank::Jack, Suit::Diamonds),
+        ]));
+        game.start_new_hand();
+
+        assert_eq!(game.players[0].status, PlayerStatus::AllIn);
+        assert_eq!(game.players[0].hole_cards.len(), 2);
+        game.execute_action(&"p2".to_string(), PlayerAction::Call).unwrap();
+        while game.hand_number == 1 && game.is_hand_in_progress() {
+            game.execute_action(&"p2".to_string(), PlayerAction::Check).unwrap();
+        }
+
+        // La paire d'As remporte le pot, Bob récupère la part non suivie de sa mise
+        let result = game.last_hand_result.as_ref().unwrap();
+        assert_eq!(result.winnings()[0].player_id, "p1");
+        assert_eq!(result.winnings()[0].amount, 30);
+        let Some(HandEvent::HandStarted { seats, .. }) = game.hand_events().first().map(|e| &e.event) else {
+            panic!("La main suivante doit commencer");
+        };
+        let stacks: Vec<u32> = seats.iter().map(|seat| seat.stack).collect();
+        assert_eq!(stacks, vec![30, 985]);
+    }
+
+    #[test]
+    fn test_hands_all_in_from_the_blinds_are_all_recorded() {
+        let mut game = GameState::new(vec![
+            ("p1".to_string(), "Alice".to_string()),
+            ("p2".to_string(), "Bob".to_string()),
+        ], 10, 10, 20);
+        game.seed = Some(1);
+        let card = |rank, suit| Card::new(rank, suit);
+        game.stack_next_deck(Deck::from_cards(vec![
+            card(Rank::Two, Suit::Clubs), card(Rank::Three, Suit::Diamonds),
+            card(Rank::Two, Suit::Diamonds), card(Rank::Three, Suit::Clubs),
+            card(Rank::Five, Suit::Clubs), // Brûlée
+            card(Rank::Ten, Suit::Spades), card(Rank::Jack, Suit::Spades), card(Rank::Queen, Suit::Spades),
+            card(Rank::Six, Suit::Diamonds), // Brûlée
+            card(Rank::King, Suit::Spades),
+            card(Rank::Eight, Suit::Hearts), // Brûlée
+            card(Rank::Ace, Suit::Spades),
+        ]));
+
+        // Les deux joueurs sont à tapis dès les blinds : la première main (quinte flush
+        // royale au tableau, pot partagé) et la suivante se jouent d'un seul coup
+        game.start_new_hand();
+        assert!(!game.is_hand_in_progress());
+        let hands = game.take_completed_hands();
+        assert_eq!(hands.iter().map(|h| h.hand_number).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(hands[0].winnings().iter().map(|w| w.amount).collect::<Vec<_>>(), vec![10, 10]);
+        assert_eq!(game.last_hand_result.as_ref(), hands.last());
+        assert!(game.take_completed_hands().is_empty());
+    }
+
+    #[test]
+    fn test_last_active_player_must_answer_an_all_in() {
+        let mut game = GameState::new(vec![
+            ("p1".to_string(), "Alice".to_string()),
+            ("p2".to_string(), "Bob".to_string()),
+        ], 1000, 10, 20);
+        game.start_new_hand();
+
+        // Le bouton fait tapis : la big blind doit encore suivre ou se coucher
+        game.execute_action(&"p2".to_string(), PlayerAction::AllIn).unwrap();
+        assert_eq!(game.phase, GamePhase::PreFlop);
+        assert_eq!(game.current_player, 0);
+        assert_eq!(game.execute_action(&"p1".to_string(), PlayerAction::Check), Err(GameError::CannotCheck));
+
+        game.execute_action(&"p1".to_string(), PlayerAction::Fold).unwrap();
+        let result = game.last_hand_result.as_ref().unwrap();
+        assert_eq!(result.winnings()[0].player_id, "p2");
+        assert_eq!(result.winnings()[0].amount, 1020); // Tapis non suivi compris
+    }
+
+    #[test]
+    fn test_heads_up_rules_apply_when_table_shrinks() {
+        let players = vec![
+            ("p1".to_string(), "Alice".to_string()),
+            ("p2".to_string(), "Bob".to_string()),
+            ("p3".to_string(), "Charlie".to_string()),
+        ];
+        let mut game = GameState::new(players, 1000, 10, 20);
+        game.players[1].chips = 0; // Bob est éliminé
+        game.start_new_hand();
+
+        // Bouton = Charlie (prochain joueur actif après 0), qui poste la small blind
+        assert_eq!(game.players[1].status, PlayerStatus::Eliminated);
+        assert_eq!(game.dealer_position, 2);
+        assert_eq!(game.small_blind_position, 2);
+        assert_eq!(game.big_blind_position, 0);
+        assert_eq!(game.current_player, 2);
+    }
+
+    fn four_players() -> Vec<(PlayerId, String)> {
+        vec![
+            ("p1".to_string(), "Alice".to_string()),
+            ("p2".to_string(), "Bob".to_string()),
+            ("p3".to_string(), "Charlie".to_string()),
+            ("p4".to_string(), "Diana".to_string()),
+        ]
+    }
+
+    #[test]
+    fn test_antes_posted_by_every_player() {
+        let blinds = BlindsConfig { ante: 5, ..BlindsConfig::new(10, 20) };
+        let mut game = GameState::with_blinds(four_players(), 1000, blinds);
+        game.start_new_hand();
+
+        assert_eq!(game.pot, 4 * 5 + 10 + 20);
+        // L'ante ne compte pas dans la mise à suivre
+        assert_eq!(game.current_bet, 20);
+        assert_eq!(game.players[game.big_blind_position].current_bet, 20);
+        assert_eq!(game.players[game.big_blind_position].total_bet, 25);
+    }
+
+    #[test]
+    fn test_big_blind_ante() {
+        let blinds = BlindsConfig { ante: 20, big_blind_ante: true, ..BlindsConfig::new(10, 20) };
+        let mut game = GameState::with_blinds(four_players(), 1000, blinds);
+        game.start_new_hand();
+
+        assert_eq!(game.pot, 10 + 20 + 20);
+        assert_eq!(game.players[game.big_blind_position].chips, 960);
+        assert_eq!(game.players[game.small_blind_position].chips, 990);
+    }
+
+    #[test]
+    fn test_straddle_gets_the_option() {
+        let blinds = BlindsConfig { straddle: true, ..BlindsConfig::new(10, 20) };
+        let mut game = GameState::with_blinds(four_players(), 1000, blinds);
+        game.start_new_hand();
+
+        // Dealer = Bob (1), SB = Charlie (2), BB = Diana (3), straddle = Alice (0)
+        assert_eq!(game.straddle_position, Some(0));
+        assert_eq!(game.current_bet, 40);
+        assert_eq!(game.raise_limits().unwrap().min_raise_to, 80);
+        assert_eq!(game.current_player, 1, "Le joueur après le straddle parle en premier");
+
+        game.execute_action(&"p2".to_string(), PlayerAction::Call).unwrap();
+        game.execute_action(&"p3".to_string(), PlayerAction::Call).unwrap();
+        game.execute_action(&"p4".to_string(), PlayerAction::Call).unwrap();
+
+        // Le straddler a l'option : il peut checker ou relancer
+        assert_eq!(game.phase, GamePhase::PreFlop);
+        assert_eq!(game.current_player, 0);
+        assert!(game.get_valid_actions().contains(&PlayerAction::Check));
+        assert!(game.raise_limits().is_some());
+    }
+
+    #[test]
+    fn test_scheduled_blinds_apply_on_next_hand() {
+        let mut game = GameState::new(four_players(), 1000, 10, 20);
+        game.start_new_hand();
+        game.schedule_blinds(BlindsConfig { ante: 5, ..BlindsConfig::new(25, 50) });
+
+        // La main en cours garde ses blinds
+        assert_eq!(game.big_blind, 20);
+
+        game.start_new_hand();
+        assert_eq!(game.big_blind, 50);
+        assert_eq!(game.ante, 5);
+        assert_eq!(game.current_bet, 50);
+        assert!(game.next_blinds.is_none());
+    }
+
+    #[test]
+    fn test_typed_action_errors() {
+        let players = vec![
+            ("p1".to_string(), "Alice".to_string()),
+            ("p2".to_string(), "Bob".to_string()),
+            ("p3".to_string(), "Charlie".to_string()),
+        ];
+        let mut game = GameState::new(players, 1000, 10, 20);
+        game.start_new_hand();
+
+        // UTG = Bob (1), qui fait face à la big blind
+        assert_eq!(game.execute_action(&"ghost".to_string(), PlayerAction::Fold), Err(GameError::PlayerNotFound));
+        assert_eq!(game.execute_action(&"p1".to_string(), PlayerAction::Fold), Err(GameError::NotYourTurn));
+        assert_eq!(game.execute_action(&"p2".to_string(), PlayerAction::Check), Err(GameError::CannotCheck));
+        assert_eq!(
+            game.execute_action(&"p2".to_string(), PlayerAction::Raise(5)),
+            Err(GameError::RaiseTooSmall { min: 20 })
+        );
+    }
+
+    #[test]
+    fn test_min_raise_is_last_raise_size() {
+        let players = vec![
+            ("p1".to_string(), "Alice".to_string()),
+            ("p2".to_string(), "Bob".to_string()),
+            ("p3".to_string(), "Charlie".to_string()),
+        ];
+        let mut game = GameState::new(players, 1000, 10, 20);
+        game.start_new_hand();
+
+        // Bob (UTG) relance de 60 : mise à 80
+        game.execute_action(&"p2".to_string(), PlayerAction::Raise(60)).unwrap();
+        assert_eq!(game.current_bet, 80);
+        assert_eq!(game.last_raise_size, 60);
+
+        // Charlie doit relancer d'au moins 60 (et non de la mise courante)
+        assert_eq!(
+            game.execute_action(&"p3".to_string(), PlayerAction::Raise(40)),
+            Err(GameError::RaiseTooSmall { min: 60 })
+        );
+        let limits = game.raise_limits().unwrap();
+        assert_eq!(limits.min_raise_to, 140);
+        assert_eq!(limits.max_raise_to, 1000, "Small blind comprise");
+        assert!(game.get_valid_actions().contains(&PlayerAction::Raise(60)));
+    }
+
+    #[test]
+    fn test_incomplete_all_in_does_not_reopen_betting() {
+        let players = vec![
+            ("p1".to_string(), "Alice".to_string()),
+            ("p2".to_string(), "Bob".to_string()),
+            ("p3".to_string(), "Charlie".to_string()),
+        ];
+        let mut game = GameState::new(players, 1000, 10, 20);
+        game.start_new_hand();
+        // Dealer = Bob, SB = Charlie, BB = Alice, UTG = Bob
+        game.players[2].chips = 110; // Charlie : 120 au total avec sa small blind
+
+        // Bob relance à 100, Charlie fait tapis à 120 (relance incomplète de 20)
+        game.execute_action(&"p2".to_string(), PlayerAction::Raise(80)).unwrap();
+        game.execute_action(&"p3".to_string(), PlayerAction::AllIn).unwrap();
+        assert_eq!(game.current_bet, 120);
+        assert_eq!(game.last_raise_size, 80);
+
+        // Alice n'a pas encore agi : elle peut relancer
+        assert_eq!(game.current_player, 0);
+        assert_eq!(game.raise_limits().unwrap().min_raise_to, 200);
+        game.execute_action(&"p1".to_string(), PlayerAction::Call).unwrap();
+
+        // Bob a déjà agi : il ne peut que suivre ou se coucher
+        assert_eq!(game.current_player, 1);
+        assert!(game.raise_limits().is_none());
+        assert_eq!(game.get_valid_actions(), vec![PlayerAction::Fold, PlayerAction::Call]);
+        assert_eq!(
+            game.execute_action(&"p2".to_string(), PlayerAction::Raise(80)),
+            Err(GameError::ActionNotReopened)
+        );
+        game.execute_action(&"p2".to_string(), PlayerAction::Call).unwrap();
+        assert_eq!(game.phase, GamePhase::Flop);
+    }
+
+    #[test]
+    fn test_postflop_round_requires_every_player_to_act() {
+        let players = vec![
+            ("p1".to_string(), "Alice".to_string()),
+            ("p2".to_string(), "Bob".to_string()),
+            ("p3".to_string(), "Charlie".to_string()),
+        ];
+        let mut game = GameState::new(players, 1000, 10, 20);
+        game.start_new_hand();
+        game.execute_action(&"p2".to_string(), PlayerAction::Call).unwrap();
+        game.execute_action(&"p3".to_string(), PlayerAction::Call).unwrap();
+        game.execute_action(&"p1".to_string(), PlayerAction::Check).unwrap();
+        assert_eq!(game.phase, GamePhase::Flop);
+
+        // Un seul check ne termine pas le tour
+        let first = game.players[game.current_player].id.clone();
+        game.execute_action(&first, PlayerAction::Check).unwrap();
+        assert_eq!(game.phase, GamePhase::Flop);
+    }
+
+    #[test]
+    fn test_short_stack_all_in_wins_only_main_pot() {
+        use crate::card::{Rank, Suit};
+
+        let players = vec![
+            ("p1".to_string(), "Short".to_string()),
+            ("p2".to_string(), "Mid".to_string()),
+            ("p3".to_string(), "Big".to_string()),
+        ];
+        let mut game = GameState::new(players, 0, 10, 20);
+
+        // Short (50) a la meilleure main, Mid (200) la deuxième, Big (500) la pire
+        game.community_cards = vec![
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Nine, Suit::Clubs),
+            Card::new(Rank::Jack, Suit::Spades),
+            Card::new(Rank::Four, Suit::Hearts),
+        ];
+        let holdings = [
+            (50, PlayerStatus::AllIn, [Card::new(Rank::Ace, Suit::Spades), Card::new(Rank::Ace, Suit::Hearts)]),
+            (200, PlayerStatus::AllIn, [Card::new(Rank::King, Suit::Spades), Card::new(Rank::King, Suit::Hearts)]),
+            (500, PlayerStatus::Active, [Card::new(Rank::Three, Suit::Spades), Card::new(Rank::Five, Suit::Clubs)]),
+        ];
+        for (player, (total_bet, status, cards)) in game.players.iter_mut().zip(holdings) {
+            player.total_bet = total_bet;
+            player.status = status;
+            player.hole_cards = cards.to_vec();
+        }
+        game.pot = 750;
+
+        game.settle_pots();
+
+        assert_eq!(game.players[0].chips, 150, "Short ne gagne que le pot principal");
+        assert_eq!(game.players[1].chips, 300, "Mid gagne le pot secondaire");
+        assert_eq!(game.players[2].chips, 300, "Big récupère sa mise non suivie");
+        assert_eq!(game.pot, 0);
+
+        let result = game.last_hand_result.as_ref().unwrap();
+        assert_eq!(result.pots.len(), 3);
+        assert_eq!(result.total(), 750);
+        assert_eq!(result.winning_hand().unwrap().player_id, "p1");
+        assert_eq!(result.winning_hand().unwrap().hand.rank, crate::hand::HandRank::OnePair);
+    }
+
+    #[test]
+    fn test_tied_hands_split_pot_with_odd_chip() {
+        use crate::card::{Rank, Suit};
+
+        let players = vec![
+            ("p1".to_string(), "Alice".to_string()),
+            ("p2".to_string(), "Bob".to_string()),
+            ("p3".to_string(), "Charlie".to_string()),
+        ];
+        let mut game = GameState::new(players, 0, 10, 20);
+        game.dealer_position = 0;
+
+        // Alice et Bob ont la même quinte au dix (couleurs différentes), Charlie est couché
+        game.community_cards = vec![
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Nine, Suit::Spades),
+            Card::new(Rank::Two, Suit::Hearts),
+        ];
+        let holdings = [
+            (50, PlayerStatus::Active, [Card::new(Rank::Ten, Suit::Spades), Card::new(Rank::Three, Suit::Hearts)]),
+            (50, PlayerStatus::Active, [Card::new(Rank::Ten, Suit::Clubs), Card::new(Rank::Three, Suit::Diamonds)]),
+            (1, PlayerStatus::Folded, [Card::new(Rank::Ace, Suit::Spades), Card::new(Rank::Ace, Suit::Hearts)]),
+        ];
+        for (player, (total_bet, status, cards)) in game.players.iter_mut().zip(holdings) {
+            player.total_bet = total_bet;
+            player.status = status;
+            player.hole_cards = cards.to_vec();
+        }
+        game.pot = 101;
+
+        game.settle_pots();
+
+        // Bob est le premier à gauche du bouton : il reçoit le jeton indivisible
+        assert_eq!(game.players[1].chips, 51);
+        assert_eq!(game.players[0].chips, 50);
+        assert_eq!(game.players[2].chips, 0);
+        let result = game.last_hand_result.as_ref().unwrap();
+        assert_eq!(result.pots.len(), 1);
+        assert_eq!(result.pots[0].winners, vec![
+            PotWinner { player_id: "p2".to_string(), amount: 51 },
+            PotWinner { player_id: "p1".to_string(), amount: 50 },
+        ]);
+        assert_eq!(result.revealed_hands.len(), 2, "La main couchée n'est pas dévoilée");
+        assert_eq!(result.description(), "Pot partagé - Quinte");
+    }
+
+    #[test]
+    fn test_force_fold_out_of_turn() {
+        let mut game = GameState::new(four_players(), 1000, 10, 20);
+        game.start_new_hand();
+        let to_act = game.players[game.current_player].id.clone();
+        let waiting = game.players.iter()
+            .find(|p| p.id != to_act && p.status == PlayerStatus::Active)
+            .unwrap().id.clone();
+
+        // L'ordre de parole ne change pas
+        game.force_fold(&waiting).unwrap();
+        assert_eq!(game.players.iter().find(|p| p.id == waiting).unwrap().status, PlayerStatus::Folded);
+        assert_eq!(game.players[game.current_player].id, to_act);
+        game.force_fold(&waiting).unwrap(); // Déjà couché : sans effet
+
+        // Tous les adversaires couchés : le dernier joueur remporte la main
+        let hand = game.hand_number;
+        let others: Vec<PlayerId> = game.players.iter()
+            .filter(|p| p.status == PlayerStatus::Active && p.id != to_act)
+            .map(|p| p.id.clone())
+            .collect();
+        for id in &others {
+            game.force_fold(id).unwrap();
+        }
+        assert_eq!(game.hand_number, hand + 1);
+        assert_eq!(game.last_hand_result.as_ref().unwrap().winnings()[0].player_id, to_act);
+        assert_eq!(game.force_fold(&"inconnu".to_string()), Err(GameError::PlayerNotFound));
+    }
+
+    #[test]
+    fn test_seat_changes_wait_for_end_of_hand() {
+        let mut game = GameState::new(four_players(), 1000, 10, 20);
+        game.start_new_hand();
+        let dealer_id = game.players[game.dealer_position].id.clone();
+
+        // Main en cours : le départ et l'arrivée sont différés
+        game.remove_player(&"p4".to_string()).unwrap();
+        game.add_player(Player::new("p5".to_string(), "Eve".to_string(), 1500));
+        assert_eq!(game.players.len(), 4);
+        assert!(game.take_removed_players().is_empty());
+        assert_eq!(game.remove_player(&"inconnu".to_string()), Err(GameError::PlayerNotFound));
+
+        game.start_new_hand();
+        let ids: Vec<_> = game.players.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["p1", "p2", "p3", "p5"]);
+        let removed = game.take_removed_players();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].id, "p4");
+        // Le bouton passe au joueur suivant l'ancien dealer
+        assert_ne!(game.players[game.dealer_position].id, dealer_id);
+    }
+
+    #[test]
+    fn test_sitting_out_player_is_skipped_and_posts_missed_big_blind() {
+        let mut game = GameState::new(four_players(), 1000, 10, 20);
+        game.sit_out(&"p4".to_string()).unwrap();
+        assert_eq!(game.players[3].status, PlayerStatus::SittingOut);
+
+        // Ni cartes ni blinds pour l'absent, quelle que soit la position du bouton
+        for _ in 0..4 {
+            game.start_new_hand();
+            assert!(game.players[3].hole_cards.is_empty());
+            assert_eq!(game.players[3].total_bet, 0);
+            assert_eq!(game.pot, 30);
+        }
+        assert!(game.players[3].missed_blinds, "La big blind a fait le tour de la table");
+
+        // Retour : servi à la main suivante, avec une big blind vivante
+        game.sit_in(&"p4".to_string()).unwrap();
+        game.start_new_hand();
+        let p4 = &game.players[3];
+        assert_eq!(p4.hole_cards.len(), 2);
+        assert!(p4.current_bet >= 20);
+        assert!(!p4.missed_blinds);
+
+        // Sans pénalité, retour gratuit
+        let mut game = GameState::new(four_players(), 1000, 10, 20);
+        game.missed_blinds_rule = MissedBlindsRule::Ignore;
+        game.sit_out(&"p4".to_string()).unwrap();
+        for _ in 0..4 {
+            game.start_new_hand();
+        }
+        assert!(!game.players[3].missed_blinds);
+
+        // En pleine main, l'absence commence à la main suivante
+        game.sit_out(&"p1".to_string()).unwrap();
+        assert_ne!(game.players[0].status, PlayerStatus::SittingOut);
+        game.start_new_hand();
+        assert_eq!(game.players[0].status, PlayerStatus::SittingOut);
+        assert_eq!(game.sit_in(&"inconnu".to_string()), Err(GameError::PlayerNotFound));
+    }
+
+    #[test]
+    fn test_chips_added_between_hands() {
+        let mut game = GameState::new(four_players(), 1000, 10, 20);
+        game.add_chips(&"p1".to_string(), 500).unwrap();
+        assert_eq!(game.players[0].chips, 1500, "Entre deux mains : immédiat");
+
+        // Pendant la main, le tapis en jeu ne change pas
+        game.start_new_hand();
+        let chips_in_hand = game.players[1].chips;
+        game.add_chips(&"p2".to_string(), 300).unwrap();
+        assert_eq!(game.players[1].chips, chips_in_hand);
+        assert_eq!(game.pending_chips(&"p2".to_string()), 300);
+
+        game.start_new_hand();
+        assert_eq!(game.players[1].chips + game.players[1].total_bet, chips_in_hand + 300);
+        assert_eq!(game.pending_chips(&"p2".to_string()), 0);
+
+        // Un joueur qui quitte la table ne peut plus acheter de jetons
+        game.remove_player(&"p3".to_string()).unwrap();
+        assert!(!game.has_player(&"p3".to_string()));
+        assert_eq!(game.add_chips(&"p3".to_string(), 100), Err(GameError::PlayerNotFound));
+    }
+
+    #[test]
+    fn test_serialized_state_resumes_the_same_hand() {
+        let mut game = GameState::new(four_players(), 1000, 10, 20);
+        game.start_new_hand();
+        let to_act = game.players[game.current_player].clone();
+        game.execute_action(&to_act.id, PlayerAction::Call).unwrap();
+
+        // Paquet compris : la suite de la main est identique après restauration
+        let mut restored: GameState = serde_json::from_str(&serde_json::to_string(&game).unwrap()).unwrap();
+        for state in [&mut game, &mut restored] {
+            while state.phase == GamePhase::PreFlop {
+                let id = state.players[state.current_player].id.clone();
+                let action = if state.get_valid_actions().contains(&PlayerAction::Check) { PlayerAction::Check } else { PlayerAction::Call };
+                state.execute_action(&id, action).unwrap();
+            }
+        }
+        assert_eq!(game.community_cards, restored.community_cards);
+
+        // Le journal de la main ne contient que ses propres événements
+        let hand = game.hand_number;
+        while game.hand_number == hand {
+            let id = game.players[game.current_player].id.clone();
+            game.execute_action(&id, PlayerAction::Fold).unwrap();
+        }
+        let events = &game.last_hand_result.as_ref().unwrap().events;
+        assert!(matches!(&events[0].event, HandEvent::HandStarted { hand_number, .. } if *hand_number == hand));
+        let first_action = events.iter().find(|e| matches!(e.event, HandEvent::ActionTaken { .. })).unwrap();
+        assert_eq!(first_action.event.describe(|_| to_act.name.clone()), format!("{} calls 20", to_act.name));
+        assert!(matches!(events.last().unwrap().event, HandEvent::PotAwarded { .. }));
+        let previous_seq = events.last().unwrap().seq;
+
+        // Main suivante : début, deux blinds, quatre donnes, trois abandons puis le gain de la big blind
+        let hand = game.hand_number;
+        while game.hand_number == hand {
+            let id = game.players[game.current_player].id.clone();
+            game.execute_action(&id, PlayerAction::Fold).unwrap();
+        }
+        let last = &game.last_hand_result.as_ref().unwrap().events;
+        assert_eq!(last.len(), 11);
+
+        // Le journal ne garde que la main en cours ; les numéros se suivent d'une main à l'autre
+        assert!(matches!(game.events[0].event, HandEvent::HandStarted { .. }));
+        assert_eq!(game.events[0].seq, last.last().unwrap().seq + 1);
+        assert_eq!(last[0].seq, previous_seq + 1);
+    }
+
+    #[test]
+    fn test_seeded_games_deal_the_same_cards() {
+        let play_hands = |seed: Option<u64>| {
+            let mut game = GameState::new(four_players(), 1000, 10, 20);
+            game.seed = seed;
+            game.start_new_hand();
+            let mut deals = Vec::new();
+            for _ in 0..3 {
+                deals.push(game.players.iter().map(|p| p.hole_cards.clone()).collect::<Vec<_>>());
+                let hand = game.hand_number;
+                while game.hand_number == hand {
+                    let id = game.players[game.current_player].id.clone();
+                    let action = if game.get_valid_actions().contains(&PlayerAction::Check) { PlayerAction::Check } else { PlayerAction::Call };
+                    game.execute_action(&id, action).unwrap();
+                }
+                deals.push(vec![game.last_hand_result.as_ref().unwrap().board.clone()]);
+            }
+            (deals, game)
+        };
+
+        let (first, game) = play_hands(Some(7));
+        let (second, _) = play_hands(Some(7));
+        assert_eq!(first, second, "Même graine, mêmes donnes");
+        assert_ne!(first, play_hands(Some(8)).0);
+
+        // La graine notée avec la main suffit à rejouer son paquet
+        let result = game.last_hand_result.as_ref().unwrap();
+        let mut deck = Deck::with_seed(result.seed.unwrap());
+        let dealt: Vec<Card> = (0..8).map(|_| deck.deal().unwrap()).collect();
+        let hole_cards = result.events.iter().find_map(|e| match &e.event {
+            HandEvent::HoleCardsDealt { cards, .. } => Some(cards.clone()),
+            _ => None,
+        });
+        assert_eq!(hole_cards, Some(vec![dealt[0], dealt[4]]), "Une carte par joueur, deux tours");
+    }
+
+    #[test]
+    fn test_stacked_deck_is_dealt_in_order() {
+        let mut game = GameState::new(vec![
+            ("p1".to_string(), "Alice".to_string()),
+            ("p2".to_string(), "Bob".to_string()),
+        ], 1000, 10, 20);
+        let cards = [Rank::Ace, Rank::King, Rank::Queen, Rank::Jack].map(|rank| Card::new(rank, Suit::Spades));
+        game.stack_next_deck(Deck::from_cards(cards.to_vec()));
+        game.start_new_hand();
+
+        assert_eq!(game.players[0].hole_cards, vec![cards[0], cards[2]]);
+        assert_eq!(game.players[1].hole_cards, vec![cards[1], cards[3]]);
+        assert_eq!(game.hand_seed, None);
+    }
+
+    #[test]
+    fn test_hand_limit_stops_the_game() {
+        let mut game = GameState::new(four_players(), 1000, 10, 20);
+        game.hand_limit = Some(2);
+        game.start_new_hand();
+        assert!(!game.hand_limit_reached());
+
+        for _ in 0..2 {
+            let hand = game.hand_number;
+            while game.hand_number == hand && game.is_hand_in_progress() {
+                let id = game.players[game.current_player].id.clone();
+                game.execute_action(&id, PlayerAction::Fold).unwrap();
+            }
+        }
+
+        // La dernière main terminée, la table reste en attente
+        assert_eq!(game.hand_number, 2);
+        assert!(game.hand_limit_reached());
+        assert_eq!(game.last_hand_result.as_ref().unwrap().hand_number, 2);
+        game.start_new_hand();
+        assert_eq!(game.hand_number, 2);
+        assert!(!game.is_hand_in_progress());
+    }
+
+    #[test]
+    fn test_event_log_records_seats_and_actions() {
+        let mut game = GameState::new(four_players(), 1000, 10, 20);
+        game.start_new_hand();
+        let hand = game.hand_number;
+        let opener = game.players[game.current_player].id.clone();
+        game.execute_action(&opener, PlayerAction::Raise(60)).unwrap();
+        while game.hand_number == hand {
+            let id = game.players[game.current_player].id.clone();
+            game.execute_action(&id, PlayerAction::Fold).unwrap();
+        }
+
+        let result = game.last_hand_result.as_ref().unwrap();
+        assert_eq!(result.seats().len(), 4);
+        assert!(result.seats().iter().all(|seat| seat.stack == 1000));
+        let dealt = result.events.iter().filter(|e| matches!(&e.event, HandEvent::HoleCardsDealt { cards, .. } if cards.len() == 2));
+        assert_eq!(dealt.count(), 4);
+
+        let blinds: Vec<BlindKind> = result.events.iter()
+            .filter_map(|e| match e.event { HandEvent::BlindPosted { blind, .. } => Some(blind), _ => None })
+            .collect();
+        assert_eq!(blinds, vec![BlindKind::SmallBlind, BlindKind::BigBlind]);
+        let actions: Vec<(ActionKind, u32, u32)> = result.events.iter()
+            .filter_map(|e| match e.event { HandEvent::ActionTaken { action, amount, total, .. } => Some((action, amount, total)), _ => None })
+            .collect();
+        assert_eq!(actions, vec![
+            (ActionKind::Raise, 80, 80),
+            (ActionKind::Fold, 0, 0),
+            (ActionKind::Fold, 0, 10), // Small blind
+            (ActionKind::Fold, 0, 20), // Big blind
+        ]);
+        assert_eq!(result.uncalled_bet(), Some((opener, 60)), "Relance non suivie au-delà de la big blind");
+    }
+
+    #[test]
+    fn test_table_waits_without_opponent() {
+        let players = vec![
+            ("p1".to_string(), "Alice".to_string()),
+            ("p2".to_string(), "Bob".to_string()),
+        ];
+        let mut game = GameState::new(players, 1000, 10, 20);
+        game.players[1].chips = 0;
+        game.start_new_hand();
+
+        assert!(!game.is_hand_in_progress());
+        assert_eq!(game.hand_number, 0);
+
+        // Entre deux mains, le départ est immédiat
+        game.remove_player(&"p2".to_string()).unwrap();
+        assert_eq!(game.players.len(), 1);
+        game.add_player(Player::new("p3".to_string(), "Charlie".to_string(), 800));
+        game.start_new_hand();
+        assert!(game.is_hand_in_progress());
+        assert_eq!(game.pot, 30);
+    }
 }
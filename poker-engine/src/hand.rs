@@ -2,6 +2,7 @@ use crate::card::{Card, Rank};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt;
 
 /// Représente les différents types de mains au poker (du plus faible au plus fort)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -18,6 +19,24 @@ pub enum HandRank {
     RoyalFlush,
 }
 
+impl fmt::Display for HandRank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            HandRank::HighCard => "Carte haute",
+            HandRank::OnePair => "Paire",
+            HandRank::TwoPair => "Double paire",
+            HandRank::ThreeOfAKind => "Brelan",
+            HandRank::Straight => "Quinte",
+            HandRank::Flush => "Couleur",
+            HandRank::FullHouse => "Full",
+            HandRank::FourOfAKind => "Carré",
+            HandRank::StraightFlush => "Quinte flush",
+            HandRank::RoyalFlush => "Quinte flush royale",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 /// Représente une main évaluée avec son rang et ses cartes de kicker
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Hand {
@@ -77,7 +96,7 @@ impl Hand {
         assert_eq!(cards.len(), 5, "Il faut exactement 5 cartes");
 
         let mut sorted_cards = cards.to_vec();
-        sorted_cards.sort_by(|a, b| b.rank.cmp(&a.rank)); // Tri décroissant
+        sorted_cards.sort_by_key(|c| std::cmp::Reverse(c.rank)); // Tri décroissant
 
         // Vérifier les différentes combinaisons
         if let Some(hand) = Self::check_royal_flush(&sorted_cards) {
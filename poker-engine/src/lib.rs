@@ -2,9 +2,17 @@ pub mod card;
 pub mod deck;
 pub mod hand;
 pub mod game;
+pub mod error;
+pub mod pot;
+pub mod result;
+pub mod event;
 
 // Ré-exporter les types principaux pour faciliter l'utilisation
 pub use card::{Card, Rank, Suit};
 pub use deck::Deck;
+pub use error::GameError;
 pub use hand::{Hand, HandRank};
-pub use game::{GameState, Player, PlayerAction, PlayerStatus, GamePhase, PlayerId};
+pub use game::{BlindsConfig, GameState, MissedBlindsRule, Player, PlayerAction, PlayerStatus, GamePhase, PlayerId, RaiseLimits};
+pub use pot::{OddChipRule, Pot};
+pub use result::{HandResult, PotResult, PotWinner, ShowdownHand};
+pub use event::{ActionKind, BlindKind, HandEvent, HandSeat, LoggedEvent};
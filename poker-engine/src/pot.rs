@@ -0,0 +1,169 @@
+use crate::game::{Player, PlayerStatus};
+use serde::{Deserialize, Serialize};
+
+/// Règle d'attribution des jetons indivisibles lors d'un partage de pot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OddChipRule {
+    #[default]
+    FirstLeftOfButton, // Premier gagnant à gauche du bouton (règle standard)
+    LowestSeat,        // Gagnant assis à la plus petite position
+}
+
+impl OddChipRule {
+    /// Partage un montant entre les gagnants ex aequo et attribue le reste selon la règle
+    ///
+    /// Retourne `(index du joueur, montant gagné)` pour chaque gagnant.
+    pub fn split(&self, amount: u32, winners: &[usize], dealer_position: usize, num_players: usize) -> Vec<(usize, u32)> {
+        if winners.is_empty() {
+            return vec![];
+        }
+
+        let mut ordered = winners.to_vec();
+        match self {
+            OddChipRule::FirstLeftOfButton => {
+                // Distance depuis le bouton : le siège juste après le dealer passe en premier
+                ordered.sort_by_key(|&idx| (idx + num_players - dealer_position - 1) % num_players);
+            }
+            OddChipRule::LowestSeat => ordered.sort_unstable(),
+        }
+
+        let share = amount / ordered.len() as u32;
+        let remainder = (amount % ordered.len() as u32) as usize;
+
+        ordered
+            .into_iter()
+            .enumerate()
+            .map(|(i, idx)| (idx, share + u32::from(i < remainder)))
+            .collect()
+    }
+}
+
+/// Représente un pot (principal ou secondaire) et les joueurs pouvant le remporter
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pot {
+    pub amount: u32,
+    pub eligible_players: Vec<usize>, // Index des joueurs dans GameState::players
+}
+
+impl Pot {
+    /// Construit le pot principal puis les pots secondaires à partir des mises totales de la main
+    ///
+    /// Chaque palier correspond à la mise totale d'un joueur encore en lice : un joueur à tapis
+    /// pour 50 ne peut gagner que 50 de chaque adversaire. Les jetons des joueurs couchés
+    /// alimentent les pots mais ne leur donnent aucun droit dessus.
+    pub fn build(players: &[Player]) -> Vec<Pot> {
+        let is_contender = |p: &Player| {
+            matches!(p.status, PlayerStatus::Active | PlayerStatus::AllIn) && p.total_bet > 0
+        };
+
+        // Paliers distincts, du plus petit au plus grand
+        let mut levels: Vec<u32> = players
+            .iter()
+            .filter(|p| is_contender(p))
+            .map(|p| p.total_bet)
+            .collect();
+        levels.sort_unstable();
+        levels.dedup();
+
+        let mut pots: Vec<Pot> = Vec::new();
+        let mut previous_level = 0;
+
+        for level in levels {
+            let amount: u32 = players
+                .iter()
+                .map(|p| p.total_bet.min(level) - p.total_bet.min(previous_level))
+                .sum();
+
+            let eligible_players = players
+                .iter()
+                .enumerate()
+                .filter(|(_, p)| is_contender(p) && p.total_bet >= level)
+                .map(|(i, _)| i)
+                .collect();
+
+            pots.push(Pot { amount, eligible_players });
+            previous_level = level;
+        }
+
+        // Jetons d'un joueur couché au-delà du plus gros palier : ils restent dans le dernier pot
+        let leftover: u32 = players
+            .iter()
+            .map(|p| p.total_bet.saturating_sub(previous_level))
+            .sum();
+        if let Some(last) = pots.last_mut() {
+            last.amount += leftover;
+        }
+
+        pots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(id: &str, total_bet: u32, status: PlayerStatus) -> Player {
+        let mut p = Player::new(id.to_string(), id.to_string(), 0);
+        p.total_bet = total_bet;
+        p.status = status;
+        p
+    }
+
+    #[test]
+    fn test_single_pot() {
+        let players = vec![
+            player("a", 100, PlayerStatus::Active),
+            player("b", 100, PlayerStatus::Active),
+        ];
+        let pots = Pot::build(&players);
+
+        assert_eq!(pots, vec![Pot { amount: 200, eligible_players: vec![0, 1] }]);
+    }
+
+    #[test]
+    fn test_three_way_all_in() {
+        let players = vec![
+            player("short", 50, PlayerStatus::AllIn),
+            player("mid", 200, PlayerStatus::AllIn),
+            player("big", 500, PlayerStatus::Active),
+        ];
+        let pots = Pot::build(&players);
+
+        assert_eq!(pots.len(), 3);
+        assert_eq!(pots[0], Pot { amount: 150, eligible_players: vec![0, 1, 2] });
+        assert_eq!(pots[1], Pot { amount: 300, eligible_players: vec![1, 2] });
+        // Mise non suivie : rendue au seul joueur éligible
+        assert_eq!(pots[2], Pot { amount: 300, eligible_players: vec![2] });
+    }
+
+    #[test]
+    fn test_split_odd_chip_left_of_button() {
+        // Dealer en 1 : le siège 2 est le premier à sa gauche, puis 0
+        let shares = OddChipRule::FirstLeftOfButton.split(101, &[0, 2], 1, 4);
+        assert_eq!(shares, vec![(2, 51), (0, 50)]);
+
+        let shares = OddChipRule::LowestSeat.split(101, &[0, 2], 1, 4);
+        assert_eq!(shares, vec![(0, 51), (2, 50)]);
+    }
+
+    #[test]
+    fn test_split_even_amount() {
+        let shares = OddChipRule::default().split(90, &[0, 1, 2], 0, 3);
+        assert!(shares.iter().all(|&(_, amount)| amount == 30));
+    }
+
+    #[test]
+    fn test_folded_chips_stay_in_pot() {
+        let players = vec![
+            player("a", 30, PlayerStatus::AllIn),
+            player("b", 80, PlayerStatus::Folded),
+            player("c", 100, PlayerStatus::Active),
+        ];
+        let pots = Pot::build(&players);
+
+        assert_eq!(pots[0], Pot { amount: 90, eligible_players: vec![0, 2] });
+        assert_eq!(pots[1], Pot { amount: 120, eligible_players: vec![2] });
+        assert_eq!(pots.iter().map(|p| p.amount).sum::<u32>(), 210);
+    }
+}
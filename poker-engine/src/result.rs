@@ -0,0 +1,176 @@
+use crate::card::Card;
+use crate::game::PlayerId;
+use crate::hand::Hand;
+use crate::event::{HandEvent, HandSeat, LoggedEvent};
+use serde::{Deserialize, Serialize};
+
+/// Main dévoilée par un joueur à l'abattage
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShowdownHand {
+    pub player_id: PlayerId,
+    pub hole_cards: Vec<Card>,
+    pub hand: Hand, // Rang et meilleure combinaison de 5 cartes
+}
+
+/// Part d'un pot remportée par un joueur
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PotWinner {
+    pub player_id: PlayerId,
+    pub amount: u32,
+}
+
+/// Attribution d'un pot (principal ou secondaire)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PotResult {
+    pub amount: u32,
+    pub eligible_players: Vec<PlayerId>,
+    pub winners: Vec<PotWinner>, // Plusieurs en cas de partage
+}
+
+/// Résultat complet d'une main terminée
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandResult {
+    pub hand_number: u32,
+    pub board: Vec<Card>,
+    pub pots: Vec<PotResult>,
+    pub revealed_hands: Vec<ShowdownHand>, // Vide si la main s'est terminée sans abattage
+    #[serde(default)]
+    pub events: Vec<LoggedEvent>, // Journal de la main, du début de la main au gain
+    #[serde(default)]
+    pub seed: Option<u64>, // Graine du paquet, pour rejouer la donne (None : paquet préparé)
+}
+
+impl HandResult {
+    /// Montant total distribué
+    pub fn total(&self) -> u32 {
+        self.pots.iter().map(|p| p.amount).sum()
+    }
+
+    /// Gains cumulés par joueur, tous pots confondus (ordre du premier gain)
+    pub fn winnings(&self) -> Vec<PotWinner> {
+        let mut totals: Vec<PotWinner> = Vec::new();
+        for winner in self.pots.iter().flat_map(|p| &p.winners) {
+            match totals.iter_mut().find(|w| w.player_id == winner.player_id) {
+                Some(total) => total.amount += winner.amount,
+                None => totals.push(winner.clone()),
+            }
+        }
+        totals
+    }
+
+    /// La main est-elle allée jusqu'à l'abattage ?
+    pub fn is_showdown(&self) -> bool {
+        !self.revealed_hands.is_empty()
+    }
+
+    /// Main du gagnant du pot principal
+    pub fn winning_hand(&self) -> Option<&ShowdownHand> {
+        let winner = self.pots.first()?.winners.first()?;
+        self.hand_of(&winner.player_id)
+    }
+
+    /// Main dévoilée par un joueur donné
+    pub fn hand_of(&self, player_id: &PlayerId) -> Option<&ShowdownHand> {
+        self.revealed_hands.iter().find(|h| &h.player_id == player_id)
+    }
+
+    /// Joueurs servis dans cette main, avec leur tapis de départ
+    pub fn seats(&self) -> &[HandSeat] {
+        self.events.iter()
+            .find_map(|e| match &e.event {
+                HandEvent::HandStarted { seats, .. } => Some(seats.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Jetons engagés par chaque joueur sur toute la main (ordre des sièges)
+    pub fn contributions(&self) -> Vec<(PlayerId, u32)> {
+        self.seats().iter()
+            .map(|seat| {
+                let total = self.events.iter()
+                    .filter_map(|e| match &e.event {
+                        HandEvent::BlindPosted { player, amount, .. } | HandEvent::ActionTaken { player, amount, .. }
+                            if player == &seat.player_id => Some(*amount),
+                        _ => None,
+                    })
+                    .sum();
+                (seat.player_id.clone(), total)
+            })
+            .collect()
+    }
+
+    /// Mise non suivie, rendue au joueur qui a le plus misé : (joueur, montant)
+    pub fn uncalled_bet(&self) -> Option<(PlayerId, u32)> {
+        let mut contributions = self.contributions();
+        contributions.sort_by_key(|c| std::cmp::Reverse(c.1));
+        match contributions.as_slice() {
+            [first, second, ..] if first.1 > second.1 => Some((first.0.clone(), first.1 - second.1)),
+            _ => None,
+        }
+    }
+
+    /// Description lisible du résultat (ex: "Quinte", "Pot partagé - Full")
+    pub fn description(&self) -> String {
+        match self.winning_hand() {
+            None => "Adversaire couché".to_string(),
+            Some(shown) if self.pots[0].winners.len() > 1 => format!("Pot partagé - {}", shown.hand.rank),
+            Some(shown) => shown.hand.rank.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{Rank, Suit};
+
+    fn winner(id: &str, amount: u32) -> PotWinner {
+        PotWinner { player_id: id.to_string(), amount }
+    }
+
+    #[test]
+    fn test_winnings_aggregate_across_pots() {
+        let result = HandResult {
+            hand_number: 1,
+            board: vec![],
+            pots: vec![
+                PotResult { amount: 150, eligible_players: vec![], winners: vec![winner("a", 75), winner("b", 75)] },
+                PotResult { amount: 300, eligible_players: vec![], winners: vec![winner("b", 300)] },
+            ],
+            revealed_hands: vec![],
+            events: vec![],
+            seed: None,
+        };
+
+        assert_eq!(result.total(), 450);
+        assert_eq!(result.winnings(), vec![winner("a", 75), winner("b", 375)]);
+        assert_eq!(result.description(), "Adversaire couché");
+    }
+
+    #[test]
+    fn test_description_uses_main_pot_hand() {
+        let cards = vec![
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Spades),
+        ];
+        let result = HandResult {
+            hand_number: 1,
+            board: cards[2..].to_vec(),
+            pots: vec![PotResult { amount: 40, eligible_players: vec![], winners: vec![winner("a", 40)] }],
+            revealed_hands: vec![ShowdownHand {
+                player_id: "a".to_string(),
+                hole_cards: cards[..2].to_vec(),
+                hand: Hand::evaluate(&cards),
+            }],
+            events: vec![],
+            seed: None,
+        };
+
+        assert!(result.is_showdown());
+        assert_eq!(result.description(), "Paire");
+    }
+}
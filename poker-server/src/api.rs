@@ -1,13 +1,60 @@
-use actix_web::{web, HttpResponse, Result};
-use crate::game_manager::GameManager;
+use actix_web::{http::StatusCode, web, HttpRequest, HttpResponse, Result};
+use crate::auth::Role;
+use crate::game_manager::{ActionError, GameManager, Subscription, Viewer};
 use crate::models::*;
+use poker_engine::GameError;
+use tokio::sync::broadcast::error::RecvError;
 use uuid::Uuid;
 
-/// POST /api/games - Créer une nouvelle partie
+/// Code d'erreur stable et statut HTTP associés à un refus du moteur
+fn game_error_status(error: &GameError) -> (StatusCode, &'static str) {
+    match error {
+        GameError::PlayerNotFound => (StatusCode::NOT_FOUND, "player_not_found"),
+        GameError::NotYourTurn => (StatusCode::CONFLICT, "not_your_turn"),
+        GameError::PlayerNotActive { .. } => (StatusCode::CONFLICT, "player_not_active"),
+        GameError::CannotCheck => (StatusCode::UNPROCESSABLE_ENTITY, "cannot_check"),
+        GameError::RaiseTooSmall { .. } => (StatusCode::UNPROCESSABLE_ENTITY, "raise_too_small"),
+        GameError::RaiseTooLarge { .. } => (StatusCode::UNPROCESSABLE_ENTITY, "raise_too_large"),
+        GameError::ActionNotReopened => (StatusCode::UNPROCESSABLE_ENTITY, "action_not_reopened"),
+    }
+}
+
+/// Vérifier que la requête porte un token admin (`Authorization: Bearer ...`)
+///
+/// Renvoie la réponse de refus : 401 sans token valide, 403 pour un token de joueur.
+fn require_admin(req: &HttpRequest) -> Option<HttpResponse> {
+    let Some(token) = bearer_token(req) else {
+        return Some(unauthorized("Token admin requis".to_string()));
+    };
+    match crate::auth::verify_token(&token) {
+        Ok(claims) if claims.role == Role::Admin => None,
+        Ok(_) => Some(HttpResponse::Forbidden().json(serde_json::json!({
+            "error": "Droits administrateur requis"
+        }))),
+        Err(e) => Some(unauthorized(e)),
+    }
+}
+
+/// POST /api/admin/token - Obtenir un token admin avec la clé de l'organisateur
+pub async fn admin_login(
+    req: web::Json<AdminLoginRequest>,
+) -> Result<HttpResponse> {
+    match crate::auth::config().admin_login(&req.admin_key).and_then(TokenResponse::new) {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(unauthorized(e)),
+    }
+}
+
+/// POST /api/games - Créer une nouvelle partie (admin)
 pub async fn create_game(
+    http_req: HttpRequest,
     game_manager: web::Data<GameManager>,
     req: web::Json<CreateGameRequest>,
 ) -> Result<HttpResponse> {
+    if let Some(response) = require_admin(&http_req) {
+        return Ok(response);
+    }
+
     match game_manager.create_game(req.into_inner()) {
         Ok(response) => Ok(HttpResponse::Ok().json(response)),
         Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
@@ -38,11 +85,16 @@ pub async fn join_game(
     }
 }
 
-/// POST /api/games/{id}/start - Démarrer une partie
+/// POST /api/games/{id}/start - Démarrer une partie (admin)
 pub async fn start_game(
+    req: HttpRequest,
     game_manager: web::Data<GameManager>,
     game_id: web::Path<Uuid>,
 ) -> Result<HttpResponse> {
+    if let Some(response) = require_admin(&req) {
+        return Ok(response);
+    }
+
     match game_manager.start_game(*game_id) {
         Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
             "success": true
@@ -61,29 +113,715 @@ pub async fn submit_action(
 ) -> Result<HttpResponse> {
     match game_manager.submit_action(*game_id, req.into_inner()) {
         Ok(response) => Ok(HttpResponse::Ok().json(response)),
-        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+        Err(ActionError::Rejected(e)) => {
+            let (status, code) = game_error_status(&e);
+            Ok(HttpResponse::build(status).json(SubmitActionResponse {
+                success: false,
+                error: Some(e.to_string()),
+                error_code: Some(code),
+            }))
+        }
+        Err(ActionError::Request(e)) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
             "error": e
         }))),
     }
 }
 
 /// GET /api/games/{id}/state - Obtenir l'état de la partie
+///
+/// Le joueur est identifié par son token (voir `request_token`). Sans token, l'état
+/// est celui d'un spectateur, sans aucune carte privée ; avec un token admin, toutes
+/// les cartes sont visibles.
 pub async fn get_game_state(
+    req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    game_id: web::Path<Uuid>,
+    query: web::Query<std::collections::HashMap<String, String>>,
+) -> Result<HttpResponse> {
+    let state = match request_token(&req, &query) {
+        Some(token) if is_admin_token(&token) => game_manager.get_admin_state(*game_id),
+        Some(token) => match game_manager.authenticate(*game_id, &token) {
+            Ok(player_id) => game_manager.get_game_state(*game_id, &player_id),
+            Err(e) => return Ok(unauthorized(e)),
+        },
+        None => game_manager.get_spectator_state(*game_id),
+    };
+
+    match state {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// GET /api/games/{id}/hands/{n}/history.txt - Historique d'une main au format PokerStars
+///
+/// Mêmes règles de visibilité que l'état : cartes du joueur avec son token, toutes
+/// les cartes avec un token admin, aucune carte privée sans token.
+pub async fn get_hand_history(
+    req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    path: web::Path<(Uuid, u32)>,
+    query: web::Query<std::collections::HashMap<String, String>>,
+) -> Result<HttpResponse> {
+    let (game_id, hand_number) = path.into_inner();
+    let history = match request_token(&req, &query) {
+        Some(token) if is_admin_token(&token) => game_manager.get_hand_history(game_id, hand_number, Viewer::Admin),
+        Some(token) => match game_manager.authenticate(game_id, &token) {
+            Ok(player_id) => game_manager.get_hand_history(game_id, hand_number, Viewer::Player(&player_id)),
+            Err(e) => return Ok(unauthorized(e)),
+        },
+        None => game_manager.get_hand_history(game_id, hand_number, Viewer::Spectator),
+    };
+
+    match history {
+        Ok(text) => Ok(HttpResponse::Ok()
+            .content_type("text/plain; charset=utf-8")
+            .body(text)),
+        Err(e) => Ok(HttpResponse::NotFound().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// GET /api/games/{id}/wait - Attendre son tour (long-poll)
+///
+/// `timeout` en secondes (défaut 30, de 1 à 60). Token requis.
+pub async fn wait_for_turn(
+    req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    game_id: web::Path<Uuid>,
+    query: web::Query<std::collections::HashMap<String, String>>,
+) -> Result<HttpResponse> {
+    let Some(token) = request_token(&req, &query) else {
+        return Ok(unauthorized("Token requis".to_string()));
+    };
+    let player_id = match game_manager.authenticate(*game_id, &token) {
+        Ok(player_id) => player_id,
+        Err(e) => return Ok(unauthorized(e)),
+    };
+    let timeout = query.get("timeout")
+        .and_then(|t| t.parse::<u64>().ok())
+        .unwrap_or(30)
+        .clamp(1, 60);
+
+    match game_manager.wait_for_turn(*game_id, &player_id, std::time::Duration::from_secs(timeout)).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// POST /api/games/{id}/sit_out - S'absenter de la table (token du joueur)
+pub async fn sit_out(
+    req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    game_id: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    let player_id = match authenticate_player(&req, &game_manager, *game_id) {
+        Ok(player_id) => player_id,
+        Err(response) => return Ok(response),
+    };
+
+    match game_manager.sit_out(*game_id, &player_id) {
+        Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "success": true
+        }))),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// POST /api/games/{id}/sit_in - Revenir à la table (token du joueur)
+pub async fn sit_in(
+    req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    game_id: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    let player_id = match authenticate_player(&req, &game_manager, *game_id) {
+        Ok(player_id) => player_id,
+        Err(response) => return Ok(response),
+    };
+
+    match game_manager.sit_in(*game_id, &player_id) {
+        Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "success": true
+        }))),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// POST /api/games/{id}/leave - Quitter la table avec son tapis (token du joueur)
+pub async fn leave_game(
+    req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    game_id: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    let player_id = match authenticate_player(&req, &game_manager, *game_id) {
+        Ok(player_id) => player_id,
+        Err(response) => return Ok(response),
+    };
+
+    match game_manager.leave_game(*game_id, &player_id) {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// POST /api/games/{id}/rebuy - Recaver après avoir perdu son tapis (cash game, token du joueur)
+pub async fn rebuy(
+    http_req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    game_id: web::Path<Uuid>,
+    req: web::Json<BuyChipsRequest>,
+) -> Result<HttpResponse> {
+    let player_id = match authenticate_player(&http_req, &game_manager, *game_id) {
+        Ok(player_id) => player_id,
+        Err(response) => return Ok(response),
+    };
+
+    match game_manager.rebuy(*game_id, &player_id, req.amount) {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// POST /api/games/{id}/top_up - Compléter son tapis (cash game, token du joueur)
+pub async fn top_up(
+    http_req: HttpRequest,
     game_manager: web::Data<GameManager>,
     game_id: web::Path<Uuid>,
+    req: web::Json<BuyChipsRequest>,
+) -> Result<HttpResponse> {
+    let player_id = match authenticate_player(&http_req, &game_manager, *game_id) {
+        Ok(player_id) => player_id,
+        Err(response) => return Ok(response),
+    };
+
+    match game_manager.top_up(*game_id, &player_id, req.amount) {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// POST /api/auth/refresh - Renouveler son token (en-tête `Authorization: Bearer ...`)
+pub async fn refresh_token(
+    req: HttpRequest,
+    game_manager: web::Data<GameManager>,
     query: web::Query<std::collections::HashMap<String, String>>,
 ) -> Result<HttpResponse> {
-    let player_id = query.get("player_id")
-        .ok_or_else(|| actix_web::error::ErrorBadRequest("player_id requis"))?;
+    let Some(token) = request_token(&req, &query) else {
+        return Ok(unauthorized("Token requis".to_string()));
+    };
 
-    match game_manager.get_game_state(*game_id, player_id) {
+    match game_manager.refresh_token(&token) {
         Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(unauthorized(e)),
+    }
+}
+
+/// Réponse 401 : token absent, invalide ou émis pour une autre partie
+fn unauthorized(error: String) -> HttpResponse {
+    HttpResponse::Unauthorized().json(serde_json::json!({
+        "error": error
+    }))
+}
+
+/// Token JWT de l'en-tête `Authorization: Bearer ...`
+fn bearer_token(req: &HttpRequest) -> Option<String> {
+    req.headers()
+        .get("Authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(|token| token.to_string())
+}
+
+/// Token JWT d'une requête : en-tête `Authorization: Bearer ...` ou paramètre `?token=`
+/// (les navigateurs ne peuvent pas ajouter d'en-tête à une connexion WebSocket)
+fn request_token(req: &HttpRequest, query: &std::collections::HashMap<String, String>) -> Option<String> {
+    bearer_token(req).or_else(|| query.get("token").cloned())
+}
+
+/// Joueur authentifié par l'en-tête `Authorization: Bearer ...` (réponse 401 sinon)
+#[allow(clippy::result_large_err)] // Réponse construite une seule fois, sur le chemin d'erreur
+fn authenticate_player(req: &HttpRequest, game_manager: &GameManager, game_id: Uuid) -> std::result::Result<String, HttpResponse> {
+    let token = bearer_token(req)
+        .ok_or_else(|| unauthorized("Token requis".to_string()))?;
+    game_manager.authenticate(game_id, &token)
+        .map_err(unauthorized)
+}
+
+fn is_admin_token(token: &str) -> bool {
+    crate::auth::verify_token(token).is_ok_and(|claims| claims.role == Role::Admin)
+}
+
+/// POST /api/games/{id}/kick - Exclure un joueur (admin)
+pub async fn kick_player(
+    http_req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    game_id: web::Path<Uuid>,
+    req: web::Json<KickPlayerRequest>,
+) -> Result<HttpResponse> {
+    if let Some(response) = require_admin(&http_req) {
+        return Ok(response);
+    }
+
+    match game_manager.kick_player(*game_id, &req.player_id) {
+        Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "success": true
+        }))),
         Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
             "error": e
         }))),
     }
 }
 
+/// POST /api/games/{id}/pause - Suspendre une partie (admin)
+pub async fn pause_game(
+    req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    game_id: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    if let Some(response) = require_admin(&req) {
+        return Ok(response);
+    }
+
+    match game_manager.pause_game(*game_id) {
+        Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "success": true
+        }))),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// POST /api/games/{id}/resume - Reprendre une partie suspendue (admin)
+pub async fn resume_game(
+    req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    game_id: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    if let Some(response) = require_admin(&req) {
+        return Ok(response);
+    }
+
+    match game_manager.resume_game(*game_id) {
+        Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "success": true
+        }))),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// POST /api/games/{id}/abort - Interrompre une partie, jetons figés (admin)
+pub async fn abort_game(
+    req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    game_id: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    if let Some(response) = require_admin(&req) {
+        return Ok(response);
+    }
+
+    match game_manager.abort_game(*game_id) {
+        Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "success": true
+        }))),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// POST /api/games/{id}/archive - Archiver une partie terminée (admin)
+pub async fn archive_game(
+    req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    game_id: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    if let Some(response) = require_admin(&req) {
+        return Ok(response);
+    }
+
+    match game_manager.archive_game(*game_id) {
+        Ok(archived) => Ok(HttpResponse::Ok().json(archived)),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// DELETE /api/games/{id} - Supprimer une partie ou une archive (admin)
+pub async fn delete_game(
+    req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    game_id: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    if let Some(response) = require_admin(&req) {
+        return Ok(response);
+    }
+
+    match game_manager.delete_game(*game_id) {
+        Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "success": true
+        }))),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// GET /api/archive - Lister les parties archivées
+pub async fn list_archived_games(
+    game_manager: web::Data<GameManager>,
+) -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(game_manager.list_archived_games()))
+}
+
+/// GET /api/games/{id}/ws - Événements de la partie poussés en temps réel
+pub async fn game_events_ws(
+    req: HttpRequest,
+    body: web::Payload,
+    game_manager: web::Data<GameManager>,
+    game_id: web::Path<Uuid>,
+    query: web::Query<std::collections::HashMap<String, String>>,
+) -> Result<HttpResponse> {
+    let Some(token) = request_token(&req, &query) else {
+        return Ok(unauthorized("Token requis".to_string()));
+    };
+
+    let Subscription { player_id, mut events, backlog } = match game_manager.subscribe(*game_id, &token, None) {
+        Ok(subscription) => subscription,
+        Err(e) => return Ok(unauthorized(e)),
+    };
+
+    let (response, mut session, mut messages) = actix_ws::handle(&req, body)?;
+
+    actix_web::rt::spawn(async move {
+        println!("🔌 WebSocket ouvert pour {} (partie {})", player_id, game_id);
+
+        for event in backlog {
+            if session.text(serde_json::to_string(&event.event).unwrap_or_default()).await.is_err() {
+                return;
+            }
+        }
+
+        loop {
+            tokio::select! {
+                message = messages.recv() => match message {
+                    Some(Ok(actix_ws::Message::Ping(bytes))) => {
+                        if session.pong(&bytes).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(Ok(actix_ws::Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => {} // Le canal est en lecture seule : les actions passent par POST /action
+                },
+                event = events.recv() => match event {
+                    Ok(event) if event.event.is_visible_to(&player_id) => {
+                        if session.text(serde_json::to_string(&event.event).unwrap_or_default()).await.is_err() {
+                            break;
+                        }
+                    }
+                    Ok(_) | Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => break,
+                },
+            }
+        }
+
+        println!("🔌 WebSocket fermé pour {}", player_id);
+        let _ = session.close(None).await;
+    });
+
+    Ok(response)
+}
+
+/// Formater un message Server-Sent Events
+fn sse_message(id: Option<u64>, event: &str, data: &impl serde::Serialize) -> web::Bytes {
+    let mut message = String::new();
+    if let Some(id) = id {
+        message.push_str(&format!("id: {}\n", id));
+    }
+    message.push_str(&format!("event: {}\ndata: {}\n\n", event, serde_json::to_string(data).unwrap_or_default()));
+    web::Bytes::from(message)
+}
+
+/// GET /api/games/{id}/events - Flux Server-Sent Events filtré pour le joueur
+///
+/// Chaque événement porte un identifiant : à la reconnexion, l'en-tête `Last-Event-ID`
+/// permet de rejouer les événements manqués. Un message `state` (sans identifiant) contient
+/// l'état complet vu par le joueur, à la connexion puis après chaque `game_update`.
+pub async fn game_events_sse(
+    req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    game_id: web::Path<Uuid>,
+    query: web::Query<std::collections::HashMap<String, String>>,
+) -> Result<HttpResponse> {
+    let Some(token) = request_token(&req, &query) else {
+        return Ok(unauthorized("Token requis".to_string()));
+    };
+
+    let last_event_id = req.headers()
+        .get("Last-Event-ID")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok());
+
+    let game_id = *game_id;
+    let Subscription { player_id, mut events, backlog } = match game_manager.subscribe(game_id, &token, last_event_id) {
+        Ok(subscription) => subscription,
+        Err(e) => return Ok(unauthorized(e)),
+    };
+
+    let (tx, mut rx) = tokio::sync::mpsc::channel::<web::Bytes>(32);
+    let manager = game_manager.clone();
+
+    actix_web::rt::spawn(async move {
+        let state_message = |player_id: &PlayerId| manager.get_game_state(game_id, player_id)
+            .ok()
+            .map(|state| sse_message(None, "state", &state));
+
+        // Rejeu des événements manqués (ou état courant), puis état complet
+        for event in backlog {
+            if tx.send(sse_message(Some(event.id), event.event.name(), &event.event)).await.is_err() {
+                return;
+            }
+        }
+        if let Some(message) = state_message(&player_id) {
+            if tx.send(message).await.is_err() {
+                return;
+            }
+        }
+
+        // Commentaire périodique : garde la connexion ouverte et détecte les clients partis
+        let period = std::time::Duration::from_secs(15);
+        let mut keep_alive = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        loop {
+            let message = tokio::select! {
+                event = events.recv() => match event {
+                    Ok(event) if event.event.is_visible_to(&player_id) => {
+                        if tx.send(sse_message(Some(event.id), event.event.name(), &event.event)).await.is_err() {
+                            break;
+                        }
+                        match event.event {
+                            GameEvent::GameUpdate { .. } => state_message(&player_id),
+                            _ => None,
+                        }
+                    }
+                    Ok(_) => None,
+                    Err(RecvError::Lagged(_)) => state_message(&player_id), // Trop lent : resynchronisation
+                    Err(RecvError::Closed) => break,
+                },
+                _ = keep_alive.tick() => Some(web::Bytes::from_static(b": keep-alive\n\n")),
+            };
+
+            if let Some(message) = message {
+                if tx.send(message).await.is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    let stream = futures_util::stream::poll_fn(move |cx| {
+        rx.poll_recv(cx).map(|message| message.map(Ok::<_, actix_web::Error>))
+    });
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header(("Cache-Control", "no-cache"))
+        .streaming(stream))
+}
+
+/// POST /api/tournaments - Créer un tournoi multi-tables (admin)
+pub async fn create_tournament(
+    http_req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    req: web::Json<CreateTournamentRequest>,
+) -> Result<HttpResponse> {
+    if let Some(response) = require_admin(&http_req) {
+        return Ok(response);
+    }
+
+    match game_manager.create_tournament(req.into_inner()) {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// POST /api/tournaments/{id}/register - S'inscrire à un tournoi
+pub async fn register_tournament(
+    game_manager: web::Data<GameManager>,
+    tournament_id: web::Path<Uuid>,
+    req: web::Json<JoinGameRequest>,
+) -> Result<HttpResponse> {
+    match game_manager.register_tournament(*tournament_id, req.into_inner()) {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// POST /api/tournaments/{id}/start - Démarrer un tournoi (admin)
+pub async fn start_tournament(
+    req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    tournament_id: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    if let Some(response) = require_admin(&req) {
+        return Ok(response);
+    }
+
+    match game_manager.start_tournament(*tournament_id) {
+        Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "success": true
+        }))),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// GET /api/tournaments/{id} - Tables, joueurs restants et places finales
+pub async fn get_tournament(
+    game_manager: web::Data<GameManager>,
+    tournament_id: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    match game_manager.get_tournament(*tournament_id) {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(HttpResponse::NotFound().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// GET /api/tournaments/{id}/standings - Places finales et points d'un tournoi
+pub async fn get_tournament_standings(
+    game_manager: web::Data<GameManager>,
+    tournament_id: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    match game_manager.get_tournament_standings(*tournament_id) {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(HttpResponse::NotFound().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// POST /api/duplicates - Créer un match duplicate (admin)
+pub async fn create_duplicate(
+    http_req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    req: web::Json<CreateDuplicateRequest>,
+) -> Result<HttpResponse> {
+    if let Some(response) = require_admin(&http_req) {
+        return Ok(response);
+    }
+
+    match game_manager.create_duplicate(req.into_inner()) {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// POST /api/duplicates/{id}/register - S'inscrire à un match duplicate
+pub async fn register_duplicate(
+    game_manager: web::Data<GameManager>,
+    duplicate_id: web::Path<Uuid>,
+    req: web::Json<JoinGameRequest>,
+) -> Result<HttpResponse> {
+    match game_manager.register_duplicate(*duplicate_id, req.into_inner()) {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// POST /api/duplicates/{id}/start - Démarrer un match duplicate (admin)
+pub async fn start_duplicate(
+    req: HttpRequest,
+    game_manager: web::Data<GameManager>,
+    duplicate_id: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    if let Some(response) = require_admin(&req) {
+        return Ok(response);
+    }
+
+    match game_manager.start_duplicate(*duplicate_id) {
+        Ok(_) => Ok(HttpResponse::Ok().json(serde_json::json!({
+            "success": true
+        }))),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// GET /api/duplicates/{id} - Tables et écarts de jetons d'un match duplicate
+pub async fn get_duplicate(
+    game_manager: web::Data<GameManager>,
+    duplicate_id: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    match game_manager.get_duplicate(*duplicate_id) {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(HttpResponse::NotFound().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// GET /api/leaderboard - Classement général par nom de bot
+pub async fn get_leaderboard(
+    game_manager: web::Data<GameManager>,
+) -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(game_manager.get_leaderboard()))
+}
+
+/// POST /api/bots - Enregistrer un bot (identifiant stable et clé d'API)
+pub async fn register_bot(
+    game_manager: web::Data<GameManager>,
+    req: web::Json<RegisterBotRequest>,
+) -> Result<HttpResponse> {
+    match game_manager.register_bot(req.into_inner()) {
+        Ok(response) => Ok(HttpResponse::Created().json(response)),
+        Err(e) => Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
+/// GET /api/bots/{bot_id} - Profil et statistiques d'un bot enregistré
+pub async fn get_bot(
+    game_manager: web::Data<GameManager>,
+    bot_id: web::Path<String>,
+) -> Result<HttpResponse> {
+    match game_manager.get_bot(&bot_id) {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => Ok(HttpResponse::NotFound().json(serde_json::json!({
+            "error": e
+        }))),
+    }
+}
+
 /// Configuration des routes API
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -93,6 +831,36 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/games/{id}/join", web::post().to(join_game))
             .route("/games/{id}/start", web::post().to(start_game))
             .route("/games/{id}/action", web::post().to(submit_action))
+            .route("/games/{id}", web::delete().to(delete_game))
+            .route("/games/{id}/kick", web::post().to(kick_player))
+            .route("/games/{id}/sit_out", web::post().to(sit_out))
+            .route("/games/{id}/sit_in", web::post().to(sit_in))
+            .route("/games/{id}/leave", web::post().to(leave_game))
+            .route("/games/{id}/rebuy", web::post().to(rebuy))
+            .route("/games/{id}/top_up", web::post().to(top_up))
+            .route("/games/{id}/pause", web::post().to(pause_game))
+            .route("/games/{id}/resume", web::post().to(resume_game))
+            .route("/games/{id}/abort", web::post().to(abort_game))
+            .route("/games/{id}/archive", web::post().to(archive_game))
             .route("/games/{id}/state", web::get().to(get_game_state))
+            .route("/games/{id}/ws", web::get().to(game_events_ws))
+            .route("/games/{id}/events", web::get().to(game_events_sse))
+            .route("/games/{id}/wait", web::get().to(wait_for_turn))
+            .route("/games/{id}/hands/{n}/history.txt", web::get().to(get_hand_history))
+            .route("/tournaments", web::post().to(create_tournament))
+            .route("/tournaments/{id}", web::get().to(get_tournament))
+            .route("/tournaments/{id}/register", web::post().to(register_tournament))
+            .route("/tournaments/{id}/start", web::post().to(start_tournament))
+            .route("/tournaments/{id}/standings", web::get().to(get_tournament_standings))
+            .route("/duplicates", web::post().to(create_duplicate))
+            .route("/duplicates/{id}", web::get().to(get_duplicate))
+            .route("/duplicates/{id}/register", web::post().to(register_duplicate))
+            .route("/duplicates/{id}/start", web::post().to(start_duplicate))
+            .route("/leaderboard", web::get().to(get_leaderboard))
+            .route("/archive", web::get().to(list_archived_games))
+            .route("/auth/refresh", web::post().to(refresh_token))
+            .route("/admin/token", web::post().to(admin_login))
+            .route("/bots", web::post().to(register_bot))
+            .route("/bots/{bot_id}", web::get().to(get_bot))
     );
 }
@@ -1,56 +1,212 @@
 use chrono::{Duration, Utc};
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use jsonwebtoken::{decode, decode_header, encode, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
 
-// Secret pour signer les JWT (à mettre en variable d'environnement en production)
-const JWT_SECRET: &[u8] = b"poker_secret_key_change_in_production";
+// Identifiant de la clé tirée au hasard quand aucune clé n'est configurée
+const GENERATED_KID: &str = "generated";
+
+/// Durée de validité par défaut d'un token (1 heure)
+const DEFAULT_TTL_SECONDS: i64 = 3600;
+
+static CONFIG: OnceLock<AuthConfig> = OnceLock::new();
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
     pub player_id: String,
     pub game_id: String,
     pub exp: i64, // Expiration timestamp
+    #[serde(default)]
+    pub role: Role,
+}
+
+/// Rôle porté par un token
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    #[default]
+    Player, // Joueur d'une partie ou d'un tournoi
+    Admin,  // Organisateur : gestion des parties, vue complète
+}
+
+/// Clé de signature identifiée par son `kid`
+#[derive(Debug, Clone)]
+pub struct SigningKey {
+    pub kid: String,
+    pub secret: String,
+}
+
+/// Configuration des tokens JWT
+///
+/// La première clé signe les nouveaux tokens ; toutes les clés restent acceptées en
+/// vérification, ce qui permet une rotation sans invalider les tokens en circulation.
+#[derive(Debug, Clone)]
+pub struct AuthConfig {
+    pub keys: Vec<SigningKey>,
+    pub ttl: Duration,
+    pub admin_key: Option<String>, // Clé échangée contre un token admin
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            keys: vec![SigningKey { kid: GENERATED_KID.to_string(), secret: random_secret() }],
+            ttl: Duration::seconds(DEFAULT_TTL_SECONDS),
+            admin_key: None,
+        }
+    }
+}
+
+impl AuthConfig {
+    /// Charger la configuration depuis l'environnement
+    ///
+    /// - `POKER_JWT_KEYS` : clés actives `kid:secret` séparées par des virgules, la première signe
+    /// - `POKER_JWT_SECRET` : clé unique (kid `default`), si `POKER_JWT_KEYS` est absent
+    /// - `POKER_JWT_TTL_SECONDS` : durée de validité des tokens (défaut 3600)
+    /// - `POKER_ADMIN_KEY` : clé de l'organisateur
+    pub fn from_env() -> Result<Self, String> {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+        let mut config = Self::from_vars(var("POKER_JWT_KEYS"), var("POKER_JWT_SECRET"), var("POKER_JWT_TTL_SECONDS"))?;
+        config.admin_key = var("POKER_ADMIN_KEY");
+        Ok(config)
+    }
+
+    fn from_vars(keys: Option<String>, secret: Option<String>, ttl: Option<String>) -> Result<Self, String> {
+        let mut config = Self::default();
+
+        if let Some(keys) = keys {
+            config.keys = keys.split(',')
+                .map(|entry| {
+                    let (kid, secret) = entry.trim().split_once(':')
+                        .ok_or_else(|| format!("POKER_JWT_KEYS: '{}' n'est pas de la forme kid:secret", entry.trim()))?;
+                    if kid.is_empty() || secret.is_empty() {
+                        return Err("POKER_JWT_KEYS: kid et secret ne peuvent pas être vides".to_string());
+                    }
+                    Ok(SigningKey { kid: kid.to_string(), secret: secret.to_string() })
+                })
+                .collect::<Result<_, String>>()?;
+        } else if let Some(secret) = secret {
+            config.keys = vec![SigningKey { kid: "default".to_string(), secret }];
+        }
+
+        if let Some(ttl) = ttl {
+            let seconds: i64 = ttl.parse()
+                .ok()
+                .filter(|s| *s > 0)
+                .ok_or_else(|| format!("POKER_JWT_TTL_SECONDS invalide: {}", ttl))?;
+            config.ttl = Duration::seconds(seconds);
+        }
+
+        Ok(config)
+    }
+
+    /// Vrai si aucune clé n'est configurée : la clé aléatoire ne vaut que pour cette exécution
+    pub fn uses_generated_key(&self) -> bool {
+        self.keys.iter().any(|key| key.kid == GENERATED_KID)
+    }
+
+    fn signing_key(&self) -> &SigningKey {
+        &self.keys[0]
+    }
+
+    /// Créer un token JWT signé avec la clé active
+    pub fn create_token(&self, player_id: &str, game_id: &str) -> Result<String, jsonwebtoken::errors::Error> {
+        self.sign(player_id, game_id, Role::Player)
+    }
+
+    /// Créer un token admin, valable sur toutes les parties
+    pub fn create_admin_token(&self) -> Result<String, jsonwebtoken::errors::Error> {
+        self.sign("admin", "*", Role::Admin)
+    }
+
+    /// Échanger la clé de l'organisateur contre un token admin
+    pub fn admin_login(&self, admin_key: &str) -> Result<String, String> {
+        let expected = self.admin_key.as_deref().ok_or("Aucune clé admin configurée")?;
+
+        // Comparaison des empreintes : durée indépendante du nombre de caractères corrects
+        let digest = |key: &str| ring::digest::digest(&ring::digest::SHA256, key.as_bytes());
+        if digest(admin_key).as_ref() != digest(expected).as_ref() {
+            return Err("Clé admin invalide".to_string());
+        }
+
+        self.create_admin_token()
+            .map_err(|e| format!("Erreur de génération de token: {}", e))
+    }
+
+    fn sign(&self, player_id: &str, game_id: &str, role: Role) -> Result<String, jsonwebtoken::errors::Error> {
+        let expiration = Utc::now()
+            .checked_add_signed(self.ttl)
+            .expect("valid timestamp")
+            .timestamp();
+
+        let claims = Claims {
+            player_id: player_id.to_string(),
+            game_id: game_id.to_string(),
+            exp: expiration,
+            role,
+        };
+
+        let key = self.signing_key();
+        let header = Header { kid: Some(key.kid.clone()), ..Header::default() };
+        encode(&header, &claims, &EncodingKey::from_secret(key.secret.as_bytes()))
+    }
+
+    /// Vérifier et décoder un token JWT avec la clé désignée par son `kid`
+    pub fn verify_token(&self, token: &str) -> Result<Claims, String> {
+        let header = decode_header(token).map_err(|_| "Token invalide".to_string())?;
+        let key = header.kid
+            .and_then(|kid| self.keys.iter().find(|key| key.kid == kid))
+            .ok_or("Clé de signature inconnue")?;
+
+        decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(key.secret.as_bytes()),
+            &Validation::default(),
+        )
+        .map(|data| data.claims)
+        .map_err(|e| match e.kind() {
+            jsonwebtoken::errors::ErrorKind::ExpiredSignature => "Token expiré".to_string(),
+            jsonwebtoken::errors::ErrorKind::InvalidToken => "Token invalide".to_string(),
+            _ => format!("Erreur de validation du token: {}", e),
+        })
+    }
+}
+
+/// Secret de 256 bits tiré au hasard, au format hexadécimal
+fn random_secret() -> String {
+    rand::random::<[u8; 32]>().iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Installer la configuration du serveur (à appeler une fois, au démarrage)
+pub fn init(config: AuthConfig) {
+    if CONFIG.set(config).is_err() {
+        eprintln!("⚠️ Configuration JWT déjà initialisée");
+    }
+}
+
+/// Configuration en vigueur (clé aléatoire si `init` n'a pas été appelé)
+pub fn config() -> &'static AuthConfig {
+    CONFIG.get_or_init(AuthConfig::default)
 }
 
 /// Créer un token JWT pour un joueur
 pub fn create_token(player_id: &str, game_id: &str) -> Result<String, jsonwebtoken::errors::Error> {
-    let expiration = Utc::now()
-        .checked_add_signed(Duration::hours(1))
-        .expect("valid timestamp")
-        .timestamp();
-
-    let claims = Claims {
-        player_id: player_id.to_string(),
-        game_id: game_id.to_string(),
-        exp: expiration,
-    };
-
-    encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(JWT_SECRET),
-    )
+    config().create_token(player_id, game_id)
 }
 
 /// Vérifier et décoder un token JWT
 pub fn verify_token(token: &str) -> Result<Claims, String> {
-    decode::<Claims>(
-        token,
-        &DecodingKey::from_secret(JWT_SECRET),
-        &Validation::default(),
-    )
-    .map(|data| data.claims)
-    .map_err(|e| match e.kind() {
-        jsonwebtoken::errors::ErrorKind::ExpiredSignature => "Token expiré".to_string(),
-        jsonwebtoken::errors::ErrorKind::InvalidToken => "Token invalide".to_string(),
-        _ => format!("Erreur de validation du token: {}", e),
-    })
+    config().verify_token(token)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn config_with(keys: &str) -> AuthConfig {
+        AuthConfig::from_vars(Some(keys.to_string()), None, None).unwrap()
+    }
+
     #[test]
     fn test_create_and_verify_token() {
         let player_id = "test_player";
@@ -68,4 +224,63 @@ mod tests {
         let result = verify_token("invalid_token");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_key_rotation() {
+        let old = config_with("k1:ancien");
+        let token = old.create_token("p", "g").unwrap();
+        assert_eq!(decode_header(&token).unwrap().kid.as_deref(), Some("k1"));
+
+        // Nouvelle clé active, l'ancienne reste acceptée le temps de la rotation
+        let rotating = config_with("k2:nouveau,k1:ancien");
+        assert_eq!(rotating.verify_token(&token).unwrap().player_id, "p");
+        let fresh = rotating.create_token("p", "g").unwrap();
+        assert_eq!(decode_header(&fresh).unwrap().kid.as_deref(), Some("k2"));
+
+        // Ancienne clé retirée
+        let rotated = config_with("k2:nouveau");
+        assert!(rotated.verify_token(&token).is_err());
+        assert!(rotated.verify_token(&fresh).is_ok());
+
+        // Même kid, secret différent : signature rejetée
+        assert!(config_with("k2:autre").verify_token(&fresh).is_err());
+    }
+
+    #[test]
+    fn test_admin_login() {
+        let mut config = AuthConfig::default();
+        assert!(config.admin_login("n'importe").is_err(), "Pas de clé admin : aucun accès");
+
+        config.admin_key = Some("organisateur".to_string());
+        assert!(config.admin_login("mauvaise").is_err());
+        let token = config.admin_login("organisateur").unwrap();
+        assert_eq!(config.verify_token(&token).unwrap().role, Role::Admin);
+
+        let player = config.create_token("p", "g").unwrap();
+        assert_eq!(config.verify_token(&player).unwrap().role, Role::Player);
+    }
+
+    #[test]
+    fn test_config_from_vars() {
+        let config = AuthConfig::from_vars(None, Some("s3cret".to_string()), Some("7200".to_string())).unwrap();
+        assert_eq!(config.keys[0].kid, "default");
+        assert_eq!(config.ttl, Duration::seconds(7200));
+        assert!(!config.uses_generated_key());
+
+        assert!(AuthConfig::from_vars(None, None, None).unwrap().uses_generated_key());
+        assert!(AuthConfig::from_vars(Some("sans_separateur".to_string()), None, None).is_err());
+        assert!(AuthConfig::from_vars(None, None, Some("-5".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_generated_key_cannot_be_forged() {
+        // Sans clé configurée, chaque exécution tire la sienne
+        let config = AuthConfig::default();
+        assert!(config.verify_token(&AuthConfig::default().create_admin_token().unwrap()).is_err());
+
+        // Un token admin signé avec l'ancienne clé de développement, connue de tous, est refusé
+        let forged = config_with("dev:poker_secret_key_change_in_production").create_admin_token().unwrap();
+        assert!(config.verify_token(&forged).is_err());
+        assert_eq!(config.verify_token(&config.create_admin_token().unwrap()).unwrap().role, Role::Admin);
+    }
 }
@@ -0,0 +1,175 @@
+use poker_engine::BlindsConfig;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime};
+
+/// Durée d'un niveau de blinds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LevelDuration {
+    Hands(u32),   // Nombre de mains jouées à ce niveau
+    Seconds(u64), // Durée en temps réel
+}
+
+/// Un niveau de la structure de blinds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlindLevel {
+    pub small_blind: u32,
+    pub big_blind: u32,
+    #[serde(default)]
+    pub ante: u32,
+    pub duration: LevelDuration,
+}
+
+/// Structure de blinds d'une partie de tournoi
+///
+/// Le dernier niveau dure indéfiniment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlindSchedule {
+    levels: Vec<BlindLevel>,
+    current: usize,
+    level_started_at: SystemTime,
+    level_first_hand: u32, // Numéro de la première main jouée à ce niveau
+}
+
+impl BlindSchedule {
+    /// Crée une structure (None si aucun niveau n'est fourni)
+    pub fn new(levels: Vec<BlindLevel>) -> Option<Self> {
+        if levels.is_empty() {
+            return None;
+        }
+        Some(BlindSchedule {
+            levels,
+            current: 0,
+            level_started_at: SystemTime::now(),
+            level_first_hand: 1,
+        })
+    }
+
+    /// Démarre le premier niveau (au lancement de la partie)
+    pub fn start(&mut self, first_hand: u32) {
+        self.current = 0;
+        self.level_started_at = SystemTime::now();
+        self.level_first_hand = first_hand;
+    }
+
+    /// Niveau en cours
+    pub fn current_level(&self) -> &BlindLevel {
+        &self.levels[self.current]
+    }
+
+    /// Numéro du niveau en cours (à partir de 1)
+    pub fn level_number(&self) -> usize {
+        self.current + 1
+    }
+
+    /// Mises forcées du niveau en cours (les options de la table sont conservées)
+    pub fn blinds(&self, base: BlindsConfig) -> BlindsConfig {
+        let level = self.current_level();
+        BlindsConfig {
+            small_blind: level.small_blind,
+            big_blind: level.big_blind,
+            ante: level.ante,
+            ..base
+        }
+    }
+
+    /// Mains restantes au niveau en cours, main courante comprise
+    pub fn hands_left(&self, current_hand: u32) -> Option<u32> {
+        match self.current_level().duration {
+            LevelDuration::Hands(count) => {
+                let played = current_hand.saturating_sub(self.level_first_hand) + 1;
+                Some(count.saturating_sub(played))
+            }
+            LevelDuration::Seconds(_) => None,
+        }
+    }
+
+    /// Temps restant au niveau en cours
+    pub fn time_left(&self) -> Option<Duration> {
+        match self.current_level().duration {
+            LevelDuration::Seconds(secs) => {
+                let elapsed = self.level_started_at.elapsed().unwrap_or_default();
+                Some(Duration::from_secs(secs).saturating_sub(elapsed))
+            }
+            LevelDuration::Hands(_) => None,
+        }
+    }
+
+    /// Prolonger le niveau en cours (temps passé en pause)
+    pub fn extend_level(&mut self, by: Duration) {
+        self.level_started_at += by;
+    }
+
+    /// Passe au niveau suivant si le niveau en cours est écoulé
+    ///
+    /// `current_hand` est la main en cours : en mode "mains", le niveau suivant est programmé
+    /// pendant la dernière main du niveau pour s'appliquer dès la main d'après.
+    /// Retourne le nouveau niveau s'il y a eu un changement.
+    pub fn check_level_up(&mut self, current_hand: u32) -> Option<BlindLevel> {
+        if self.current + 1 >= self.levels.len() {
+            return None;
+        }
+
+        let expired = match self.current_level().duration {
+            LevelDuration::Hands(_) => self.hands_left(current_hand) == Some(0),
+            LevelDuration::Seconds(_) => self.time_left() == Some(Duration::ZERO),
+        };
+        if !expired {
+            return None;
+        }
+
+        self.current += 1;
+        self.level_started_at = SystemTime::now();
+        self.level_first_hand = current_hand + 1;
+        Some(*self.current_level())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(small_blind: u32, big_blind: u32, duration: LevelDuration) -> BlindLevel {
+        BlindLevel { small_blind, big_blind, ante: 0, duration }
+    }
+
+    #[test]
+    fn test_hand_count_level_up() {
+        let mut schedule = BlindSchedule::new(vec![
+            level(10, 20, LevelDuration::Hands(3)),
+            level(20, 40, LevelDuration::Hands(3)),
+        ]).unwrap();
+        schedule.start(1);
+
+        assert_eq!(schedule.check_level_up(1), None);
+        assert_eq!(schedule.hands_left(2), Some(1));
+        assert_eq!(schedule.check_level_up(2), None);
+
+        // Pendant la 3e main, le niveau 2 est programmé pour la main 4
+        let next = schedule.check_level_up(3).unwrap();
+        assert_eq!(next.big_blind, 40);
+        assert_eq!(schedule.level_number(), 2);
+        assert_eq!(schedule.hands_left(4), Some(2));
+
+        // Dernier niveau : il dure indéfiniment
+        assert_eq!(schedule.check_level_up(10), None);
+    }
+
+    #[test]
+    fn test_timed_level_up() {
+        let mut schedule = BlindSchedule::new(vec![
+            level(10, 20, LevelDuration::Seconds(0)),
+            level(20, 40, LevelDuration::Seconds(600)),
+        ]).unwrap();
+        schedule.start(1);
+
+        assert!(schedule.check_level_up(1).is_some());
+        assert!(schedule.time_left().unwrap() > Duration::from_secs(590));
+        assert_eq!(schedule.hands_left(1), None);
+    }
+
+    #[test]
+    fn test_empty_schedule() {
+        assert!(BlindSchedule::new(vec![]).is_none());
+    }
+}
@@ -0,0 +1,161 @@
+use crate::models::BotId;
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+use uuid::Uuid;
+
+/// Itérations PBKDF2 : la clé est aléatoire (256 bits), le hachage n'a pas besoin d'être lent
+const PBKDF2_ITERATIONS: u32 = 10_000;
+
+/// Préfixe des clés d'API, pour les repérer facilement (fichiers de config, logs)
+const API_KEY_PREFIX: &str = "pk_";
+
+/// Compte de bot enregistré
+///
+/// Seul le hachage salé de la clé d'API est conservé ; la clé elle-même n'est
+/// communiquée qu'une fois, à l'enregistrement.
+#[derive(Debug, Clone)]
+pub struct BotAccount {
+    pub bot_id: BotId,
+    pub name: String,
+    pub created_at: i64,
+    salt: Vec<u8>,
+    api_key_hash: Vec<u8>,
+}
+
+impl BotAccount {
+    /// Recréer un compte à partir de son enregistrement (sel et hachage de la clé d'API)
+    pub fn restore(bot_id: BotId, name: String, created_at: i64, salt: Vec<u8>, api_key_hash: Vec<u8>) -> Self {
+        Self { bot_id, name, created_at, salt, api_key_hash }
+    }
+
+    /// Sel et hachage de la clé d'API, pour l'enregistrement du compte
+    pub fn credentials(&self) -> (&[u8], &[u8]) {
+        (&self.salt, &self.api_key_hash)
+    }
+}
+
+/// Registre des bots enregistrés
+pub struct BotRegistry {
+    accounts: HashMap<BotId, BotAccount>,
+    rng: SystemRandom,
+}
+
+impl BotRegistry {
+    pub fn new() -> Self {
+        Self {
+            accounts: HashMap::new(),
+            rng: SystemRandom::new(),
+        }
+    }
+
+    /// Enregistrer un bot : renvoie le compte et sa clé d'API en clair
+    pub fn register(&mut self, name: &str) -> Result<(BotAccount, String), String> {
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > 32 {
+            return Err("Le nom du bot doit faire entre 1 et 32 caractères".to_string());
+        }
+        if self.is_registered_name(name) {
+            return Err(format!("Le nom {} est déjà pris", name));
+        }
+
+        let mut secret = [0u8; 32];
+        let mut salt = vec![0u8; 16];
+        self.rng.fill(&mut secret).map_err(|_| "Erreur de génération de clé".to_string())?;
+        self.rng.fill(&mut salt).map_err(|_| "Erreur de génération de clé".to_string())?;
+        let api_key = format!("{}{}", API_KEY_PREFIX, to_hex(&secret));
+
+        let account = BotAccount {
+            bot_id: format!("{}_{}", name, Uuid::new_v4()),
+            name: name.to_string(),
+            created_at: chrono::Utc::now().timestamp(),
+            api_key_hash: hash_api_key(&salt, &api_key),
+            salt,
+        };
+
+        println!("🤖 Bot enregistré: {} ({})", account.name, account.bot_id);
+        self.accounts.insert(account.bot_id.clone(), account.clone());
+        Ok((account, api_key))
+    }
+
+    /// Vérifier la clé d'API d'un bot
+    pub fn authenticate(&self, bot_id: &str, api_key: &str) -> Result<&BotAccount, String> {
+        let account = self.accounts.get(bot_id)
+            .ok_or("Bot inconnu")?;
+
+        pbkdf2::verify(
+            pbkdf2::PBKDF2_HMAC_SHA256,
+            iterations(),
+            &account.salt,
+            api_key.as_bytes(),
+            &account.api_key_hash,
+        )
+        .map_err(|_| "Clé d'API invalide".to_string())?;
+
+        Ok(account)
+    }
+
+    /// Ajouter un compte existant (restauration au démarrage)
+    pub fn insert(&mut self, account: BotAccount) {
+        self.accounts.insert(account.bot_id.clone(), account);
+    }
+
+    /// Retirer un compte (enregistrement annulé)
+    pub fn remove(&mut self, bot_id: &str) {
+        self.accounts.remove(bot_id);
+    }
+
+    pub fn get(&self, bot_id: &str) -> Option<&BotAccount> {
+        self.accounts.get(bot_id)
+    }
+
+    /// Vrai si le nom (sans tenir compte de la casse) appartient à un bot enregistré
+    pub fn is_registered_name(&self, name: &str) -> bool {
+        let name = name.trim().to_lowercase();
+        self.accounts.values().any(|account| account.name.to_lowercase() == name)
+    }
+}
+
+fn iterations() -> NonZeroU32 {
+    NonZeroU32::new(PBKDF2_ITERATIONS).unwrap()
+}
+
+fn hash_api_key(salt: &[u8], api_key: &str) -> Vec<u8> {
+    let mut hash = vec![0u8; ring::digest::SHA256_OUTPUT_LEN];
+    pbkdf2::derive(pbkdf2::PBKDF2_HMAC_SHA256, iterations(), salt, api_key.as_bytes(), &mut hash);
+    hash
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_and_authenticate() {
+        let mut registry = BotRegistry::new();
+        let (account, api_key) = registry.register("Alpha").unwrap();
+
+        assert!(api_key.starts_with(API_KEY_PREFIX));
+        assert_ne!(account.api_key_hash, api_key.as_bytes(), "La clé n'est jamais stockée en clair");
+        assert_eq!(registry.authenticate(&account.bot_id, &api_key).unwrap().name, "Alpha");
+
+        assert!(registry.authenticate(&account.bot_id, "pk_mauvaise").is_err());
+        assert!(registry.authenticate("inconnu", &api_key).is_err());
+    }
+
+    #[test]
+    fn test_names_are_unique() {
+        let mut registry = BotRegistry::new();
+        registry.register("Alpha").unwrap();
+
+        assert!(registry.register("alpha").is_err());
+        assert!(registry.register("  ").is_err());
+        assert!(registry.is_registered_name("ALPHA"));
+        assert!(!registry.is_registered_name("Beta"));
+    }
+}
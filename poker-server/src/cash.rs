@@ -0,0 +1,127 @@
+use crate::models::{CashResult, PlayerId};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Cave minimale par défaut, en big blinds
+pub fn default_min_buy_in_bb() -> u32 {
+    40
+}
+
+/// Cave maximale par défaut, en big blinds
+pub fn default_max_buy_in_bb() -> u32 {
+    100
+}
+
+/// Table de cash game : limites de cave et comptabilité des jetons achetés et encaissés
+///
+/// Les jetons achetés (cave, recave, complément) et encaissés au départ de la table
+/// donnent le résultat net de chaque joueur. La partie ne se termine jamais d'elle-même.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashTable {
+    pub min_buy_in_bb: u32,
+    pub max_buy_in_bb: u32,
+    bought_in: HashMap<PlayerId, u32>,
+    cashed_out: HashMap<PlayerId, u32>,
+}
+
+impl CashTable {
+    pub fn new(min_buy_in_bb: u32, max_buy_in_bb: u32) -> Result<Self, String> {
+        if min_buy_in_bb == 0 || min_buy_in_bb > max_buy_in_bb {
+            return Err("Limites de cave invalides (0 < min_buy_in_bb <= max_buy_in_bb)".to_string());
+        }
+        Ok(Self {
+            min_buy_in_bb,
+            max_buy_in_bb,
+            bought_in: HashMap::new(),
+            cashed_out: HashMap::new(),
+        })
+    }
+
+    /// Cave minimale et maximale en jetons
+    pub fn limits(&self, big_blind: u32) -> (u32, u32) {
+        (self.min_buy_in_bb * big_blind, self.max_buy_in_bb * big_blind)
+    }
+
+    /// Vérifier un achat de `amount` jetons pour un tapis actuel de `stack`
+    ///
+    /// Une cave (tapis vide) doit atteindre le minimum ; le tapis ne dépasse jamais le maximum.
+    pub fn check_buy_in(&self, amount: u32, stack: u32, big_blind: u32) -> Result<(), String> {
+        let (min, max) = self.limits(big_blind);
+        if amount == 0 {
+            return Err("Montant invalide".to_string());
+        }
+        if stack == 0 && amount < min {
+            return Err(format!("Cave minimale: {} jetons", min));
+        }
+        if stack + amount > max {
+            return Err(format!("Tapis maximal: {} jetons (actuellement {})", max, stack));
+        }
+        Ok(())
+    }
+
+    pub fn record_buy_in(&mut self, player_id: &PlayerId, amount: u32) {
+        *self.bought_in.entry(player_id.clone()).or_insert(0) += amount;
+    }
+
+    pub fn record_cash_out(&mut self, player_id: &PlayerId, chips: u32) {
+        *self.cashed_out.entry(player_id.clone()).or_insert(0) += chips;
+    }
+
+    /// Résultat net de chaque joueur passé à la table, du plus gros gain à la plus grosse perte
+    ///
+    /// `stack` donne le tapis actuel d'un joueur encore assis (0 sinon).
+    pub fn results(&self, name: impl Fn(&PlayerId) -> String, stack: impl Fn(&PlayerId) -> u32) -> Vec<CashResult> {
+        let mut results: Vec<CashResult> = self.bought_in.iter()
+            .map(|(player_id, &bought_in)| {
+                let cashed_out = self.cashed_out.get(player_id).copied().unwrap_or(0);
+                let chips = stack(player_id);
+                CashResult {
+                    player_id: player_id.clone(),
+                    name: name(player_id),
+                    bought_in,
+                    cashed_out,
+                    chips,
+                    net_profit: i64::from(chips) + i64::from(cashed_out) - i64::from(bought_in),
+                }
+            })
+            .collect();
+        results.sort_by(|a, b| b.net_profit.cmp(&a.net_profit).then_with(|| a.name.cmp(&b.name)));
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buy_in_limits() {
+        let table = CashTable::new(40, 100).unwrap();
+        assert_eq!(table.limits(20), (800, 2000));
+
+        assert!(table.check_buy_in(800, 0, 20).is_ok());
+        assert!(table.check_buy_in(500, 0, 20).is_err(), "Cave sous le minimum");
+        assert!(table.check_buy_in(2500, 0, 20).is_err(), "Cave au-dessus du maximum");
+        assert!(table.check_buy_in(300, 1700, 20).is_ok(), "Complément jusqu'au maximum");
+        assert!(table.check_buy_in(301, 1700, 20).is_err());
+        assert!(table.check_buy_in(0, 100, 20).is_err());
+
+        assert!(CashTable::new(0, 100).is_err());
+        assert!(CashTable::new(100, 40).is_err());
+    }
+
+    #[test]
+    fn test_net_profit() {
+        let mut table = CashTable::new(40, 100).unwrap();
+        let (alice, bob) = ("alice".to_string(), "bob".to_string());
+        table.record_buy_in(&alice, 1000);
+        table.record_buy_in(&alice, 1000); // Recave
+        table.record_cash_out(&alice, 2500);
+        table.record_buy_in(&bob, 2000);
+
+        let results = table.results(|id| id.to_uppercase(), |id| if id == "bob" { 1500 } else { 0 });
+        assert_eq!(results[0].name, "ALICE");
+        assert_eq!(results[0].net_profit, 500);
+        assert_eq!(results[1].net_profit, -500);
+    }
+}
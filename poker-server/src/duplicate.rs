@@ -0,0 +1,324 @@
+use crate::game_manager::GameRoom;
+use crate::models::*;
+use crate::tournament::Entrant;
+use poker_engine::{BlindsConfig, HandEvent};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Table d'un match duplicate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateTable {
+    pub game_id: GameId,
+    pub name: String,
+    pub seats: Vec<PlayerId>, // Joueurs dans l'ordre des sièges
+    pub hands_played: u32,
+    pub final_stacks: Option<HashMap<PlayerId, u32>>, // Tapis relevés à la fin de la table
+}
+
+/// Match duplicate
+///
+/// Les mêmes donnes sont jouées à une table par rotation des sièges : avec une graine
+/// commune, un siège reçoit les mêmes cartes à chaque table et chaque joueur occupe tour
+/// à tour tous les sièges (deux tables en tête-à-tête, cartes échangées). Le résultat d'un
+/// joueur est la somme de ses gains et pertes de jetons sur toutes les tables, ce qui
+/// neutralise la chance de la distribution.
+///
+/// Les tables sont jouées l'une après l'autre, la suivante n'étant ouverte qu'une fois la
+/// précédente close : un joueur assis à deux tables en même temps y lirait ses propres cartes,
+/// qui sont celles de ses adversaires à l'autre table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateMatch {
+    pub id: DuplicateId,
+    pub name: String,
+    pub starting_chips: u32,
+    pub blinds: BlindsConfig,
+    pub hands: u32, // Mains jouées à chaque table
+    pub seed: u64,  // Graine commune des paquets
+    pub entrants: Vec<Entrant>, // Ordre d'inscription (sièges de la première table)
+    pub tables: Vec<DuplicateTable>,
+    pub started: bool,
+    pub finished: bool,
+}
+
+impl DuplicateMatch {
+    pub fn new(id: DuplicateId, name: String, starting_chips: u32, blinds: BlindsConfig, hands: u32, seed: u64) -> Self {
+        DuplicateMatch {
+            id,
+            name,
+            starting_chips,
+            blinds,
+            hands,
+            seed,
+            entrants: Vec::new(),
+            tables: Vec::new(),
+            started: false,
+            finished: false,
+        }
+    }
+
+    /// Inscrire un joueur (avant le début du match)
+    pub fn register(&mut self, entrant: Entrant) -> Result<(), String> {
+        if self.started {
+            return Err("Le match a déjà commencé".to_string());
+        }
+
+        if self.entrants.iter().any(|e| e.player_id == entrant.player_id) {
+            return Err("Ce joueur est déjà inscrit".to_string());
+        }
+
+        let name = entrant.name.clone();
+        self.entrants.push(entrant);
+
+        println!("✅ {} s'est inscrit au match duplicate {} ({} inscrits)", name, self.name, self.entrants.len());
+        Ok(())
+    }
+
+    /// Ouvrir la première table et y distribuer la première main
+    pub fn start(&mut self, games: &mut HashMap<GameId, GameRoom>) -> Result<(), String> {
+        if self.started {
+            return Err("Le match a déjà commencé".to_string());
+        }
+
+        if self.entrants.len() < 2 {
+            return Err("Il faut au moins 2 joueurs pour commencer".to_string());
+        }
+
+        self.open_table(games)?;
+        self.started = true;
+        let count = self.entrants.len();
+        println!("🏁 Match duplicate {} démarré : {} joueurs, {} tables de {} mains", self.name, count, count, self.hands);
+        Ok(())
+    }
+
+    /// Ouvrir la table suivante, sièges décalés d'un cran, et y distribuer la première main
+    fn open_table(&mut self, games: &mut HashMap<GameId, GameRoom>) -> Result<(), String> {
+        let count = self.entrants.len();
+        let rotation = self.tables.len();
+        let mut room = GameRoom::new(
+            Uuid::new_v4(),
+            format!("{} - Table {}", self.name, rotation + 1),
+            count,
+            self.starting_chips,
+            self.blinds,
+        );
+        room.duplicate_id = Some(self.id);
+        room.state.seed = Some(self.seed);
+        room.state.hand_limit = Some(self.hands);
+
+        // Le siège i revient au joueur i + rotation : chacun occupe tous les sièges
+        let seats: Vec<PlayerId> = (0..count)
+            .map(|seat| self.entrants[(seat + rotation) % count].player_id.clone())
+            .collect();
+        for player_id in &seats {
+            let entrant = self.entrants.iter().find(|e| &e.player_id == player_id).unwrap();
+            room.add_player(entrant.player_id.clone(), entrant.name.clone(), entrant.player_type, None)?;
+        }
+        room.start_game()?;
+
+        self.tables.push(DuplicateTable {
+            game_id: room.id,
+            name: room.name.clone(),
+            seats,
+            hands_played: 0,
+            final_stacks: None,
+        });
+        games.insert(room.id, room);
+        Ok(())
+    }
+
+    /// Faire avancer le match après une action ou pendant la maintenance
+    ///
+    /// Une fois toutes ses mains jouées (ou s'il n'y reste qu'un joueur), la table en cours
+    /// est close et la suivante ouverte ; le match se termine avec la dernière table.
+    /// Retourne true si une table a été close.
+    pub fn update(&mut self, games: &mut HashMap<GameId, GameRoom>) -> bool {
+        if !self.started || self.finished {
+            return false;
+        }
+
+        let Some(table) = self.tables.last_mut() else {
+            return false;
+        };
+        let Some(room) = games.get_mut(&table.game_id) else {
+            return false;
+        };
+        table.hands_played = room.state.hand_number;
+        if !room.game_finished && !room.state.hand_limit_reached() {
+            return false;
+        }
+
+        if !room.game_finished {
+            room.game_finished = true;
+            room.state.note(format!("🏁 {} hands played", room.state.hand_number));
+            room.publish_state();
+        }
+        table.final_stacks = Some(stacks(room));
+        println!("🏁 {} close après {} mains", table.name, table.hands_played);
+
+        if self.tables.len() < self.entrants.len() {
+            if let Err(e) = self.open_table(games) {
+                println!("❌ Impossible d'ouvrir la table suivante de {}: {}", self.name, e);
+            }
+        } else {
+            self.finished = true;
+            if let Some(best) = self.standings(games).first() {
+                println!("🏆 MATCH DUPLICATE {} TERMINÉ - Meilleur écart: {} ({:+})", self.name, best.name, best.chip_differential);
+            }
+        }
+        true
+    }
+
+    /// Écart de jetons de chaque joueur, du meilleur au moins bon
+    pub fn standings(&self, games: &HashMap<GameId, GameRoom>) -> Vec<DuplicateStanding> {
+        let table_stacks: Vec<HashMap<PlayerId, u32>> = self.tables.iter()
+            .map(|table| match &table.final_stacks {
+                Some(final_stacks) => final_stacks.clone(),
+                None => games.get(&table.game_id).map(stacks).unwrap_or_default(),
+            })
+            .collect();
+
+        let mut standings: Vec<DuplicateStanding> = self.entrants.iter()
+            .map(|entrant| {
+                let table_differentials: Vec<i64> = table_stacks.iter()
+                    .map(|stacks| stacks.get(&entrant.player_id).copied().unwrap_or(0) as i64 - self.starting_chips as i64)
+                    .collect();
+                DuplicateStanding {
+                    player_id: entrant.player_id.clone(),
+                    name: entrant.name.clone(),
+                    bot_id: entrant.bot_id.clone(),
+                    chip_differential: table_differentials.iter().sum(),
+                    table_differentials,
+                }
+            })
+            .collect();
+        standings.sort_by_key(|s| std::cmp::Reverse(s.chip_differential));
+        standings
+    }
+
+    /// État du match au format API (la graine n'est dévoilée qu'à la fin)
+    pub fn status(&self, games: &HashMap<GameId, GameRoom>) -> DuplicateStatusResponse {
+        DuplicateStatusResponse {
+            duplicate_id: self.id,
+            name: self.name.clone(),
+            hands: self.hands,
+            seed: self.finished.then_some(self.seed),
+            started: self.started,
+            finished: self.finished,
+            tables: self.tables.iter().map(|table| DuplicateTableInfo {
+                game_id: table.game_id,
+                name: table.name.clone(),
+                seats: table.seats.clone(),
+                hands_played: table.hands_played,
+                finished: table.final_stacks.is_some(),
+            }).collect(),
+            standings: self.standings(games),
+        }
+    }
+}
+
+/// Tapis des joueurs d'une table, pris au début de la main en cours s'il y en a une
+fn stacks(room: &GameRoom) -> HashMap<PlayerId, u32> {
+    let mut stacks: HashMap<PlayerId, u32> = room.state.players.iter()
+        .map(|p| (p.id.clone(), p.chips))
+        .collect();
+    if room.state.is_hand_in_progress() {
+        if let Some(HandEvent::HandStarted { seats, .. }) = room.state.hand_events().first().map(|e| &e.event) {
+            stacks.extend(seats.iter().map(|seat| (seat.player_id.clone(), seat.stack)));
+        }
+    }
+    stacks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poker_engine::PlayerAction;
+
+    fn duplicate(players: usize, hands: u32) -> (DuplicateMatch, HashMap<GameId, GameRoom>) {
+        let mut duplicate = DuplicateMatch::new(Uuid::new_v4(), "Test".to_string(), 1000, BlindsConfig::new(10, 20), hands, 42);
+        for i in 0..players {
+            duplicate.register(Entrant {
+                player_id: format!("Bot{}_id", i),
+                name: format!("Bot{}", i),
+                player_type: PlayerType::Bot,
+                bot_id: None,
+            }).unwrap();
+        }
+        let mut games = HashMap::new();
+        duplicate.start(&mut games).unwrap();
+        (duplicate, games)
+    }
+
+    /// Jouer la main en cours d'une table, le premier à parler relançant et les autres se couchant
+    fn play_hand(room: &mut GameRoom) {
+        let hand = room.state.hand_number;
+        let opener = room.state.players[room.state.current_player].id.clone();
+        room.state.execute_action(&opener, PlayerAction::Raise(40)).unwrap();
+        while room.state.hand_number == hand && room.state.is_hand_in_progress() {
+            let id = room.state.players[room.state.current_player].id.clone();
+            room.state.execute_action(&id, PlayerAction::Fold).unwrap();
+        }
+    }
+
+    fn hole_cards(room: &GameRoom) -> Vec<Vec<poker_engine::Card>> {
+        room.state.players.iter().map(|p| p.hole_cards.clone()).collect()
+    }
+
+    #[test]
+    fn test_seats_rotate_over_the_same_cards() {
+        let (mut duplicate, mut games) = duplicate(3, 1);
+        assert_eq!(duplicate.tables.len(), 1, "Une seule table ouverte à la fois");
+        let mut cards = Vec::new();
+        while !duplicate.finished {
+            let room = games.get_mut(&duplicate.tables.last().unwrap().game_id).unwrap();
+            assert_eq!(room.duplicate_id, Some(duplicate.id));
+            cards.push(hole_cards(room));
+            play_hand(room);
+            assert!(duplicate.update(&mut games));
+        }
+
+        assert_eq!(duplicate.tables.len(), 3);
+        let seats: Vec<&Vec<PlayerId>> = duplicate.tables.iter().map(|t| &t.seats).collect();
+        assert_eq!(seats[0], &vec!["Bot0_id".to_string(), "Bot1_id".to_string(), "Bot2_id".to_string()]);
+        assert_eq!(seats[1], &vec!["Bot1_id".to_string(), "Bot2_id".to_string(), "Bot0_id".to_string()]);
+
+        // Même graine : les sièges reçoivent les mêmes cartes à chaque table
+        assert_eq!(cards[0], cards[1]);
+        assert_eq!(cards[0], cards[2]);
+    }
+
+    #[test]
+    fn test_tables_play_one_after_another_and_differentials_add_up() {
+        let (mut duplicate, mut games) = duplicate(2, 3);
+        let first = duplicate.tables[0].game_id;
+        let dealt = hole_cards(&games[&first]);
+
+        // La seconde table n'est ouverte qu'une fois toutes les mains de la première jouées
+        for hand in 1..=3 {
+            assert_eq!(games[&first].state.hand_number, hand);
+            play_hand(games.get_mut(&first).unwrap());
+            assert_eq!(duplicate.update(&mut games), hand == 3);
+        }
+        assert!(games[&first].game_finished);
+        assert_eq!(duplicate.tables.len(), 2);
+        assert_eq!(duplicate.status(&games).seed, None);
+
+        let second = duplicate.tables[1].game_id;
+        assert_eq!(games[&second].state.hand_number, 1);
+        assert_eq!(hole_cards(&games[&second]), dealt);
+        for _ in 0..3 {
+            play_hand(games.get_mut(&second).unwrap());
+            duplicate.update(&mut games);
+        }
+        assert!(duplicate.finished);
+        assert!(games.values().all(|room| room.game_finished && !room.state.is_hand_in_progress()));
+
+        // Les mêmes actions à chaque siège : les écarts s'annulent
+        let standings = duplicate.standings(&games);
+        assert_eq!(standings.len(), 2);
+        assert!(standings.iter().all(|s| s.chip_differential == 0 && s.table_differentials.len() == 2));
+        assert_eq!(standings[0].table_differentials.iter().map(|d| d.abs()).collect::<Vec<_>>(), vec![20, 20]);
+        assert_eq!(duplicate.status(&games).seed, Some(42));
+    }
+}
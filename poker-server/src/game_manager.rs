@@ -1,8 +1,17 @@
 use crate::models::*;
-use crate::auth::{create_token};  // Importer la fonction de création de token
-use poker_engine::GameState;
-use std::collections::HashMap;
+use crate::blind_schedule::{BlindLevel, BlindSchedule};
+use crate::tournament::{Entrant, Tournament};
+use crate::duplicate::DuplicateMatch;
+use crate::scoring::{self, points_for};
+use crate::auth::{create_token, Role};  // Importer la fonction de création de token
+use crate::bots::BotRegistry;
+use crate::cash::CashTable;
+use crate::hand_history;
+use crate::storage::Storage;
+use poker_engine::{BlindsConfig, GameError, GameState, HandEvent, HandResult, LoggedEvent, MissedBlindsRule, Player};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::{Arc, Mutex};
+use tokio::sync::{broadcast, watch};
 use uuid::Uuid;
 
 /// Salle de jeu contenant l'état et les métadonnées
@@ -11,82 +20,288 @@ pub struct GameRoom {
     pub id: GameId,
     pub name: String,
     pub max_players: usize,
+    pub starting_chips: u32, // Tapis de départ, y compris pour une arrivée en cours de partie
+    pub late_join: bool,     // Places libres ouvertes pendant la partie
     pub state: GameState,
     pub player_names: HashMap<PlayerId, String>,
     pub player_types: HashMap<PlayerId, PlayerType>,
+    pub registered_bots: HashSet<PlayerId>, // Joueurs qui sont des bots enregistrés (player_id = bot_id)
     pub started: bool,
     pub game_finished: bool,
+    pub paused_at: Option<std::time::SystemTime>, // Partie suspendue par l'organisateur
+    pub aborted: bool,                            // Interrompue : jetons figés, hors classement
+    pub finished_at: Option<std::time::SystemTime>, // Relevé par la maintenance, pour l'archivage
     pub winner_id: Option<PlayerId>,
-    pub action_log: Vec<String>,
-    pub last_hand_winner: Option<PlayerId>,
-    pub last_hand_amount: Option<u32>,
-    pub last_hand_description: Option<String>,
-    pub last_hand_cards: Option<Vec<String>>,
+    pub hand_history: Vec<HandResult>, // Mains terminées, de la première à la dernière
     pub last_action_time: std::time::SystemTime,
     pub player_timeout_strikes: HashMap<PlayerId, u32>,
+    pub blind_schedule: Option<BlindSchedule>, // Structure de blinds (None = blinds fixes)
+    pub cash: Option<CashTable>,               // Table de cash game (None = partie à élimination)
+    pub tournament_id: Option<TournamentId>,   // Table d'un tournoi multi-tables
+    pub duplicate_id: Option<DuplicateId>,     // Table d'un match duplicate
+    pub finishing_positions: Vec<(PlayerId, usize)>, // Places finales, dans l'ordre de sortie
+    pub points_table: Vec<u32>,                // Points attribués à la 1re, 2e... place
+    pub events: broadcast::Sender<SequencedEvent>, // Événements poussés aux clients (WebSocket, SSE)
+    pub last_event_id: u64,
+    pub event_history: VecDeque<SequencedEvent>, // Derniers événements, rejoués à la reconnexion
+    pub changes: watch::Sender<u64>, // Notification de changement (dernier événement publié), pour le long-poll
+}
+
+/// Point de vue d'une requête d'état
+#[derive(Debug, Clone, Copy)]
+pub enum Viewer<'a> {
+    Player(&'a PlayerId),
+    Spectator,
+    Admin, // Organisateur : toutes les cartes visibles
+}
+
+impl Viewer<'_> {
+    /// L'événement du journal est-il visible de ce point de vue ?
+    pub fn can_see(&self, event: &HandEvent) -> bool {
+        match self {
+            Viewer::Player(player_id) => event.is_visible_to(Some(player_id)),
+            Viewer::Spectator => event.is_visible_to(None),
+            Viewer::Admin => true,
+        }
+    }
+
+    /// Événements du journal visibles de ce point de vue (cartes privées filtrées)
+    pub fn visible_events(&self, events: &[LoggedEvent]) -> Vec<LoggedEvent> {
+        events.iter().filter(|e| self.can_see(&e.event)).cloned().collect()
+    }
+}
+
+/// Nombre d'événements conservés par partie pour la reprise après déconnexion
+const EVENT_HISTORY_SIZE: usize = 256;
+
+/// Abonnement aux événements d'une partie
+pub struct Subscription {
+    pub player_id: PlayerId,
+    pub events: broadcast::Receiver<SequencedEvent>,
+    pub backlog: Vec<SequencedEvent>, // À envoyer avant les nouveaux événements
 }
 
 impl GameRoom {
-    pub fn new(id: GameId, name: String, max_players: usize, starting_chips: u32, small_blind: u32, big_blind: u32) -> Self {
+    pub fn new(id: GameId, name: String, max_players: usize, starting_chips: u32, blinds: BlindsConfig) -> Self {
         GameRoom {
             id,
             name,
             max_players,
-            state: GameState::new(vec![], starting_chips, small_blind, big_blind),
+            starting_chips,
+            late_join: false,
+            state: GameState::with_blinds(vec![], starting_chips, blinds),
             player_names: HashMap::new(),
             player_types: HashMap::new(),
+            registered_bots: HashSet::new(),
             started: false,
             game_finished: false,
+            paused_at: None,
+            aborted: false,
+            finished_at: None,
             winner_id: None,
-            action_log: Vec::new(),
-            last_hand_winner: None,
-            last_hand_amount: None,
-            last_hand_description: None,
-            last_hand_cards: None,
+            hand_history: Vec::new(),
             last_action_time: std::time::SystemTime::now(),
             player_timeout_strikes: HashMap::new(),
+            blind_schedule: None,
+            cash: None,
+            tournament_id: None,
+            duplicate_id: None,
+            finishing_positions: Vec::new(),
+            points_table: scoring::default_points_table(),
+            events: broadcast::channel(64).0,
+            last_event_id: 0,
+            event_history: VecDeque::new(),
+            changes: watch::channel(0).0,
         }
     }
 
-    /// Ajouter un joueur à la partie
-    pub fn add_player(&mut self, player_id: PlayerId, name: String, player_type: PlayerType) -> Result<usize, String> {
+    /// Numéroter, conserver et diffuser un événement aux clients connectés
+    pub fn publish(&mut self, event: GameEvent) {
+        self.record_completed_hands();
+        self.last_event_id += 1;
+        let event = SequencedEvent { id: self.last_event_id, event };
+
+        if self.event_history.len() >= EVENT_HISTORY_SIZE {
+            self.event_history.pop_front();
+        }
+        self.event_history.push_back(event.clone());
+        let _ = self.events.send(event); // Ignoré si personne n'écoute
+        self.changes.send_replace(self.last_event_id);
+    }
+
+    /// Ajouter à l'historique les mains que le moteur vient de terminer
+    ///
+    /// Toute fin de main est suivie d'une publication : l'historique est complété à ce moment.
+    fn record_completed_hands(&mut self) {
+        self.hand_history.extend(self.state.take_completed_hands());
+    }
+
+    /// Événements visibles par un joueur publiés après `last_id`
+    ///
+    /// None si une partie des événements manqués n'est plus dans l'historique.
+    pub fn events_since(&self, last_id: u64, player_id: &PlayerId) -> Option<Vec<SequencedEvent>> {
+        let oldest = self.event_history.front().map_or(self.last_event_id + 1, |e| e.id);
+        if last_id > self.last_event_id || last_id + 1 < oldest {
+            return None;
+        }
+
+        Some(self.event_history.iter()
+            .filter(|e| e.id > last_id && e.event.is_visible_to(player_id))
+            .cloned()
+            .collect())
+    }
+
+    /// Diffuser l'état public de la table, puis prévenir le joueur dont c'est le tour
+    pub fn publish_state(&mut self) {
+        self.publish(self.update_event());
+        if let Some(event) = self.turn_event() {
+            self.publish(event);
+        }
+    }
+
+    /// Fin de partie : annoncer le vainqueur
+    pub fn publish_end(&mut self) {
+        if let Some(winner_id) = &self.winner_id {
+            self.publish(GameEvent::GameEnded {
+                game_id: self.id,
+                winner: self.display_name(winner_id),
+            });
+        }
+    }
+
+    /// État public de la table
+    pub fn update_event(&self) -> GameEvent {
+        GameEvent::GameUpdate {
+            game_id: self.id,
+            phase: phase_to_string(&self.state.phase),
+            pot: self.state.pot,
+            community_cards: self.state.community_cards.iter().map(card_to_string).collect(),
+            current_player: self.current_player_id(),
+        }
+    }
+
+    /// Événement privé "à vous de jouer" (None si personne ne doit agir)
+    pub fn turn_event(&self) -> Option<GameEvent> {
+        if !self.started || self.game_finished || self.is_paused() || !self.state.is_hand_in_progress() {
+            return None;
+        }
+
+        let player = self.state.players.get(self.state.current_player)?;
+        if player.status != poker_engine::PlayerStatus::Active {
+            return None;
+        }
+
+        let raise_limits = self.state.raise_limits();
+        Some(GameEvent::YourTurn {
+            game_id: self.id,
+            player_id: player.id.clone(),
+            hole_cards: player.hole_cards.iter().map(card_to_string).collect(),
+            chips: player.chips,
+            to_call: self.state.current_bet.saturating_sub(player.current_bet).min(player.chips),
+            valid_actions: self.state.get_valid_actions().iter().map(action_to_string).collect(),
+            min_raise_to: raise_limits.map(|l| l.min_raise_to),
+            max_raise_to: raise_limits.map(|l| l.max_raise_to),
+        })
+    }
+
+    /// Un token émis pour cette partie (ou pour son tournoi ou son match duplicate) est-il valable ici ?
+    /// Un token de tournoi est valable sur toutes les tables du tournoi ; celui d'un match duplicate
+    /// seulement à la table en jeu, les tables closes ayant distribué les mêmes cartes.
+    pub fn accepts_token_for(&self, token_game_id: &str) -> bool {
+        token_game_id == self.id.to_string()
+            || self.tournament_id.is_some_and(|id| token_game_id == id.to_string())
+            || self.duplicate_id.is_some_and(|id| token_game_id == id.to_string() && !self.game_finished)
+    }
+
+    /// Identifier le joueur porteur d'un token JWT émis pour cette table
+    pub fn authenticate(&self, token: &str) -> Result<PlayerId, String> {
+        let claims = crate::auth::verify_token(token)?;
+        if claims.role != Role::Player || !self.accepts_token_for(&claims.game_id) {
+            return Err("Token invalide pour cette partie".to_string());
+        }
+        Ok(claims.player_id)
+    }
+
+    /// Joueur dont c'est le tour
+    fn current_player_id(&self) -> Option<PlayerId> {
         if self.started {
+            self.state.players.get(self.state.current_player).map(|p| p.id.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Utiliser une structure de blinds de tournoi (le premier niveau remplace les blinds fixes)
+    pub fn set_blind_levels(&mut self, levels: Vec<BlindLevel>) {
+        self.blind_schedule = BlindSchedule::new(levels);
+        if let Some(schedule) = &self.blind_schedule {
+            let blinds = schedule.blinds(self.state.blinds());
+            self.state.set_blinds(blinds);
+        }
+    }
+
+    /// Ajouter un joueur à la partie
+    pub fn add_player(&mut self, player_id: PlayerId, name: String, player_type: PlayerType, buy_in: Option<u32>) -> Result<usize, String> {
+        if self.game_finished {
+            return Err("La partie est terminée".to_string());
+        }
+
+        if self.started && !self.late_join {
             return Err("La partie a déjà commencé".to_string());
         }
 
-        if self.state.players.len() >= self.max_players {
+        if self.state.seat_count() >= self.max_players {
             return Err("La partie est pleine".to_string());
         }
 
-        if self.player_names.contains_key(&player_id) {
+        // En cash game, un joueur parti peut revenir s'asseoir
+        if self.state.has_player(&player_id) || (self.cash.is_none() && self.player_names.contains_key(&player_id)) {
             return Err("Ce joueur est déjà dans la partie".to_string());
         }
 
-        let position = self.state.players.len();
+        let chips = match &self.cash {
+            Some(cash) => {
+                let amount = buy_in.unwrap_or(self.starting_chips);
+                cash.check_buy_in(amount, 0, self.state.big_blind)?;
+                amount
+            }
+            None if buy_in.is_some() => return Err("buy_in est réservé aux tables de cash game".to_string()),
+            None => self.starting_chips,
+        };
+
+        let position = self.state.seat_count();
         self.player_names.insert(player_id.clone(), name.clone());
         self.player_types.insert(player_id.clone(), player_type);
         
-        // Ajouter le joueur au GameState
-        let starting_chips = self.state.players.first()
-            .map(|p| p.chips)
-            .unwrap_or(1000);
-        
-        self.state.players.push(poker_engine::Player::new(
-            player_id.clone(),
-            name.clone(),
-            starting_chips,
-        ));
+        // Ajouter le joueur au GameState (en cours de partie : dès la fin de la main en cours,
+        // en postant une big blind si la table l'exige)
+        let mut player = poker_engine::Player::new(player_id.clone(), name.clone(), chips);
+        player.missed_blinds = self.started && self.state.missed_blinds_rule == MissedBlindsRule::PostBigBlind;
+        self.state.add_player(player);
+        if let Some(cash) = self.cash.as_mut() {
+            cash.record_buy_in(&player_id, chips);
+        }
 
         println!("✅ {} a rejoint la partie {} ({}/{})", 
             name, 
             self.name,
-            self.state.players.len(), 
+            self.state.seat_count(), 
             self.max_players
         );
 
         Ok(position)
     }
 
+    /// Asseoir un joueur venant d'une autre table, avec son tapis (dès la prochaine main)
+    pub fn seat_player(&mut self, player: Player, player_type: PlayerType) {
+        self.player_names.insert(player.id.clone(), player.name.clone());
+        self.player_types.insert(player.id.clone(), player_type);
+
+        println!("🔀 {} s'assoit à la table {} ({} jetons)", player.name, self.name, player.chips);
+        self.state.note(format!("🔀 {} joins the table ({} chips)", player.name, player.chips));
+        self.state.add_player(player);
+    }
+
     /// Démarrer la partie
     pub fn start_game(&mut self) -> Result<(), String> {
         if self.started {
@@ -99,7 +314,11 @@ impl GameRoom {
 
         self.started = true;
         self.last_action_time = std::time::SystemTime::now(); // Reset du timer
+        if let Some(schedule) = self.blind_schedule.as_mut() {
+            schedule.start(self.state.hand_number + 1);
+        }
         self.state.start_new_hand();
+        self.check_blind_level();
         
         // Log de démarrage
         let player_list: Vec<_> = self.state.players.iter()
@@ -114,14 +333,177 @@ impl GameRoom {
         for (i, name) in player_list.iter().enumerate() {
             println!("   {}. {}", i + 1, name);
         }
-        println!(""); 
+        println!();
         
         Ok(())
     }
 
+    /// Faire monter le niveau de blinds si le niveau en cours est écoulé
+    /// (le nouveau niveau s'applique à partir de la main suivante)
+    pub fn check_blind_level(&mut self) -> bool {
+        if !self.started || self.game_finished || self.is_paused() {
+            return false;
+        }
+
+        let Some(schedule) = self.blind_schedule.as_mut() else {
+            return false;
+        };
+        let Some(level) = schedule.check_level_up(self.state.hand_number) else {
+            return false;
+        };
+        let level_number = schedule.level_number();
+        self.state.schedule_blinds(schedule.blinds(self.state.blinds()));
+
+        println!("⬆️  LEVEL UP - {} : niveau {} (blinds {}/{}, ante {})",
+            self.name, level_number, level.small_blind, level.big_blind, level.ante
+        );
+        self.state.note(format!(
+            "⬆️ Level up: niveau {} - blinds {}/{} ante {} (main suivante)",
+            level_number, level.small_blind, level.big_blind, level.ante
+        ));
+        true
+    }
+
+    /// Enregistrer la place finale d'un joueur (une seule fois)
+    pub fn record_finish(&mut self, player_id: &PlayerId, position: usize) {
+        if self.finishing_positions.iter().any(|(id, _)| id == player_id) {
+            return;
+        }
+        self.finishing_positions.push((player_id.clone(), position));
+    }
+
+    /// Enregistrer la sortie d'un joueur à la prochaine place libre (en partant de la dernière)
+    fn record_bust_out(&mut self, player_id: &PlayerId) {
+        // Tous les joueurs assis depuis le début, y compris ceux qui ont déjà quitté la table
+        let position = self.player_names.len().saturating_sub(self.finishing_positions.len());
+        self.record_finish(player_id, position);
+
+        let name = self.display_name(player_id);
+        println!("💀 {} éliminé de {} ({}e)", name, self.name, position);
+        self.state.note(format!("💀 {} busted ({}th)", name, position));
+    }
+
+    /// Enregistrer les joueurs qui viennent de perdre leur tapis
+    /// (en tournoi, c'est l'orchestrateur qui attribue les places)
+    pub fn record_busts(&mut self) {
+        // Pas de classement en cash game : un joueur sans jetons peut recaver
+        if self.tournament_id.is_some() || self.cash.is_some() {
+            return;
+        }
+
+        let mut busted: Vec<PlayerId> = self.state.players.iter()
+            .filter(|p| p.chips == 0 && p.status == poker_engine::PlayerStatus::Eliminated)
+            .filter(|p| !self.finishing_positions.iter().any(|(id, _)| id == &p.id))
+            .map(|p| p.id.clone())
+            .collect();
+        self.sort_busts(&mut busted);
+        for player_id in busted {
+            self.record_bust_out(&player_id);
+        }
+    }
+
+    /// Ranger des joueurs éliminés dans l'ordre de sortie
+    ///
+    /// Un joueur sorti lors d'une main antérieure passe en premier ; pendant la même main,
+    /// le plus petit tapis de début de main sort avant les autres (et prend la moins bonne place).
+    pub fn sort_busts(&self, busted: &mut [PlayerId]) {
+        busted.sort_by_key(|player_id| self.last_hand_stack(player_id));
+    }
+
+    /// Numéro de la dernière main jouée par un joueur et son tapis au début de cette main
+    fn last_hand_stack(&self, player_id: &PlayerId) -> Option<(u32, u32)> {
+        self.hand_history.iter().rev().find_map(|hand| match hand.events.first().map(|e| &e.event) {
+            Some(HandEvent::HandStarted { hand_number, seats, .. }) => seats.iter()
+                .find(|seat| &seat.player_id == player_id)
+                .map(|seat| (*hand_number, seat.stack)),
+            _ => None,
+        })
+    }
+
+    /// Places finales connues et points du barème, de la meilleure à la moins bonne
+    pub fn standings(&self) -> Vec<FinishingPosition> {
+        let mut standings: Vec<FinishingPosition> = self.finishing_positions.iter()
+            .map(|(player_id, position)| FinishingPosition {
+                position: *position,
+                player_id: player_id.clone(),
+                name: self.display_name(player_id),
+                points: points_for(&self.points_table, *position),
+                bot_id: self.registered_bots.contains(player_id).then(|| player_id.clone()),
+            })
+            .collect();
+        standings.sort_by_key(|f| f.position);
+        standings
+    }
+
+    /// Nom affiché d'un joueur
+    fn display_name(&self, player_id: &PlayerId) -> String {
+        self.player_names.get(player_id).cloned().unwrap_or_else(|| player_id.clone())
+    }
+
+    /// Dernières lignes du journal lisible (sans les cartes privées)
+    ///
+    /// Le moteur ne garde que la main en cours : la main précédente est reprise de l'historique.
+    pub fn log_lines(&self, limit: usize) -> Vec<String> {
+        let previous = self.hand_history.last()
+            .filter(|hand| hand.hand_number < self.state.hand_number)
+            .map(|hand| hand.events.as_slice())
+            .unwrap_or_default();
+        let public: Vec<&LoggedEvent> = previous.iter()
+            .chain(self.state.hand_events())
+            .filter(|e| !e.event.is_private())
+            .collect();
+        public[public.len().saturating_sub(limit)..].iter()
+            .map(|e| e.event.describe(|id| self.display_name(id)))
+            .collect()
+    }
+
+    /// Convertir le résultat de main du moteur au format API
+    fn hand_result_info(&self, result: &HandResult, viewer: Viewer) -> HandResultInfo {
+        HandResultInfo {
+            hand_number: result.hand_number,
+            board: result.board.iter().map(card_to_string).collect(),
+            description: result.description(),
+            pots: result.pots.iter().map(|pot| PotResultInfo {
+                amount: pot.amount,
+                winners: pot.winners.iter().map(|w| PotWinnerInfo {
+                    player_id: w.player_id.clone(),
+                    name: self.display_name(&w.player_id),
+                    amount: w.amount,
+                }).collect(),
+            }).collect(),
+            revealed_hands: result.revealed_hands.iter().map(|shown| ShowdownHandInfo {
+                player_id: shown.player_id.clone(),
+                name: self.display_name(&shown.player_id),
+                hole_cards: shown.hole_cards.iter().map(card_to_string).collect(),
+                rank: shown.hand.rank,
+                description: shown.hand.rank.to_string(),
+                best_five: shown.hand.cards.iter().map(card_to_string).collect(),
+            }).collect(),
+            events: viewer.visible_events(&result.events),
+            // Une graine choisie à la main (42...) se retrouve par force brute à partir de celle
+            // d'une main : publiée seulement à la fin de la partie, et jamais aux tables d'un
+            // match duplicate, dont les autres tables rejouent les mêmes paquets
+            seed: result.seed.filter(|_| {
+                matches!(viewer, Viewer::Admin) || (self.game_finished && self.duplicate_id.is_none())
+            }),
+        }
+    }
+
     /// Obtenir l'état du jeu pour un joueur spécifique
     pub fn get_state_for_player(&self, player_id: &PlayerId) -> GameStateResponse {
-        let player = self.state.players.iter().find(|p| &p.id == player_id);
+        self.get_state_for_viewer(Viewer::Player(player_id))
+    }
+
+    /// État de la partie selon celui qui la regarde
+    ///
+    /// Un joueur ne reçoit que ses propres cartes, un spectateur aucune carte privée ;
+    /// seul l'organisateur voit toutes les cartes.
+    pub fn get_state_for_viewer(&self, viewer: Viewer) -> GameStateResponse {
+        let viewer_id = match viewer {
+            Viewer::Player(id) => Some(id),
+            Viewer::Spectator | Viewer::Admin => None,
+        };
+        let player = viewer_id.and_then(|id| self.state.players.iter().find(|p| &p.id == id));
         
         let your_cards = player.map(|p| {
             p.hole_cards.iter().map(card_to_string).collect()
@@ -129,29 +511,32 @@ impl GameRoom {
 
         let your_chips = player.map(|p| p.chips);
 
-        let current_player_id = if self.started {
-            Some(self.state.players[self.state.current_player].id.clone())
-        } else {
-            None
-        };
+        let current_player_id = self.current_player_id();
 
-        let valid_actions = if self.started && current_player_id.as_ref() == Some(player_id) {
+        let is_viewer_turn = self.started && viewer_id.is_some() && current_player_id.as_ref() == viewer_id;
+
+        let valid_actions = if is_viewer_turn {
             self.state.get_valid_actions()
                 .iter()
-                .map(|a| match a {
-                    poker_engine::PlayerAction::Fold => "fold".to_string(),
-                    poker_engine::PlayerAction::Check => "check".to_string(),
-                    poker_engine::PlayerAction::Call => "call".to_string(),
-                    poker_engine::PlayerAction::Raise(_) => "raise".to_string(),
-                    poker_engine::PlayerAction::AllIn => "allin".to_string(),
-                })
+                .map(action_to_string)
                 .collect()
         } else {
             vec![]
         };
 
+        // Bornes de relance exactes, uniquement pour le joueur dont c'est le tour
+        let raise_limits = if is_viewer_turn {
+            self.state.raise_limits()
+        } else {
+            None
+        };
+
         // SÉCURITÉ: Mode spectateur supprimé - aucune carte d'adversaire n'est jamais envoyée
 
+        // Résultat de la main précédente, produit par le moteur
+        let last_result = self.state.last_hand_result.as_ref();
+        let last_winnings = last_result.map(|r| r.winnings()).unwrap_or_default();
+
         GameStateResponse {
             game_id: self.id,
             phase: phase_to_string(&self.state.phase),
@@ -168,10 +553,13 @@ impl GameRoom {
             community_cards: self.state.community_cards.iter().map(card_to_string).collect(),
             players: self.state.players.iter().map(|p| {
                 // SÉCURITÉ: Montrer les cartes seulement si c'est le joueur lui-même
-                // OU si le demandeur n'est pas un joueur (mode spectateur/admin)
-                let is_requester_player = self.state.players.iter().any(|pl| pl.id == *player_id);
-                let show_cards = p.id == *player_id || !is_requester_player;
-                
+                let show_cards = match viewer {
+                    Viewer::Player(id) => id == &p.id,
+                    Viewer::Spectator => false,
+                    Viewer::Admin => true,
+                };
+
+
                 PlayerInfo {
                     id: p.id.clone(),
                     name: self.player_names.get(&p.id).cloned().unwrap_or_else(|| p.name.clone()),
@@ -187,29 +575,64 @@ impl GameRoom {
                 }
             }).collect(),
             current_player_id,
-            your_player_id: Some(player_id.clone()),
+            your_player_id: viewer_id.cloned(),
             your_chips,
             your_cards,
             valid_actions,
+            small_blind: self.state.small_blind,
+            big_blind: self.state.big_blind,
+            ante: self.state.ante,
+            blind_level: self.blind_schedule.as_ref().map(|schedule| {
+                let level = schedule.current_level();
+                BlindLevelInfo {
+                    level: schedule.level_number(),
+                    small_blind: level.small_blind,
+                    big_blind: level.big_blind,
+                    ante: level.ante,
+                    hands_left: schedule.hands_left(self.state.hand_number),
+                    seconds_left: schedule.time_left().map(|t| t.as_secs()),
+                }
+            }),
+            min_raise_to: raise_limits.map(|l| l.min_raise_to),
+            max_raise_to: raise_limits.map(|l| l.max_raise_to),
+            game_type: self.game_type(),
+            cash: self.cash.as_ref().map(|cash| {
+                let (min_buy_in, max_buy_in) = cash.limits(self.state.big_blind);
+                CashTableInfo {
+                    min_buy_in,
+                    max_buy_in,
+                    results: cash.results(
+                        |id| self.display_name(id),
+                        |id| self.stack_of(id).unwrap_or(0),
+                    ),
+                }
+            }),
             game_finished: self.game_finished,
+            paused: self.is_paused(),
+            aborted: self.aborted,
             winner_id: self.winner_id.clone(),
             winner_name: self.winner_id.as_ref()
                 .and_then(|id| self.player_names.get(id).cloned()),
             // Toujours envoyer le log (limité aux 50 dernières actions pour ne pas surcharger)
-            action_log: Some(
-                self.action_log.iter()
-                    .rev()
-                    .take(50)
-                    .rev()
-                    .cloned()
-                    .collect()
-            ),
-            last_hand_winner: self.last_hand_winner.clone(),
-            last_hand_winner_name: self.last_hand_winner.as_ref()
-                .and_then(|id| self.player_names.get(id).cloned()),
-            last_hand_amount: self.last_hand_amount,
-            last_hand_description: self.last_hand_description.clone(),
-            last_hand_cards: self.last_hand_cards.clone(),
+            action_log: Some(self.log_lines(50)),
+            hand_events: viewer.visible_events(self.state.hand_events()),
+            last_hand_winner: last_winnings.first().map(|w| w.player_id.clone()),
+            last_hand_winner_name: last_result.map(|_| {
+                // Pot partagé : tous les gagnants
+                last_winnings.iter()
+                    .map(|w| self.display_name(&w.player_id))
+                    .collect::<Vec<_>>()
+                    .join(" & ")
+            }),
+            last_hand_winners: last_winnings.iter().map(|w| w.player_id.clone()).collect(),
+            last_hand_amount: last_result.map(|r| r.total()),
+            last_hand_description: last_result.map(|r| r.description()),
+            last_hand_cards: last_result.map(|r| {
+                r.winning_hand()
+                    .map(|shown| shown.hand.cards.iter().map(card_to_string).collect())
+                    .unwrap_or_default()
+            }),
+            last_hand_result: last_result.map(|r| self.hand_result_info(r, viewer)),
         }
     }
 
@@ -222,12 +645,318 @@ impl GameRoom {
             max_players: self.max_players,
             phase: phase_to_string(&self.state.phase),
             pot: self.state.pot,
+            game_type: self.game_type(),
+            paused: self.is_paused(),
+            aborted: self.aborted,
+        }
+    }
+
+    /// Résumé conservé après la libération de la salle
+    pub fn archive(&self) -> ArchivedGame {
+        let finished_at = self.finished_at.unwrap_or_else(std::time::SystemTime::now);
+        ArchivedGame {
+            game_id: self.id,
+            name: self.name.clone(),
+            tournament_id: self.tournament_id,
+            duplicate_id: self.duplicate_id,
+            finished_at: chrono::DateTime::<chrono::Utc>::from(finished_at).timestamp(),
+            aborted: self.aborted,
+            hands_played: self.state.hand_number,
+            winner_name: self.winner_id.as_ref().map(|id| self.display_name(id)),
+            standings: if self.aborted { Vec::new() } else { self.standings() },
+        }
+    }
+
+    pub fn game_type(&self) -> GameType {
+        if self.cash.is_some() {
+            GameType::Cash
+        } else {
+            GameType::Freezeout
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    /// Suspendre la partie (organisateur) : plus d'actions ni de timeouts jusqu'à la reprise
+    pub fn pause(&mut self) -> Result<(), String> {
+        if !self.started {
+            return Err("La partie n'a pas encore commencé".to_string());
+        }
+        if self.game_finished {
+            return Err("La partie est terminée".to_string());
+        }
+        if self.is_paused() {
+            return Err("La partie est déjà en pause".to_string());
+        }
+
+        self.paused_at = Some(std::time::SystemTime::now());
+        println!("⏸️  {} mise en pause par l'organisateur", self.name);
+        self.state.note("⏸️ Game paused");
+        self.publish(GameEvent::GamePaused { game_id: self.id });
+        self.publish(self.update_event());
+        Ok(())
+    }
+
+    /// Reprendre la partie : le joueur qui a la parole repart avec un temps complet
+    pub fn resume(&mut self) -> Result<(), String> {
+        let paused_at = self.paused_at.take()
+            .ok_or("La partie n'est pas en pause")?;
+
+        // Le niveau de blinds en cours ne s'écoule pas pendant la pause
+        if let Some(schedule) = self.blind_schedule.as_mut() {
+            schedule.extend_level(paused_at.elapsed().unwrap_or_default());
+        }
+        self.last_action_time = std::time::SystemTime::now();
+
+        println!("▶️  {} reprend", self.name);
+        self.state.note("▶️ Game resumed");
+        self.publish(GameEvent::GameResumed { game_id: self.id });
+        self.publish_state();
+        Ok(())
+    }
+
+    /// Interrompre définitivement la partie (organisateur)
+    ///
+    /// Les jetons restent tels quels, main en cours comprise : pas de vainqueur et aucun
+    /// point attribué au classement.
+    pub fn abort(&mut self) -> Result<(), String> {
+        if self.tournament_id.is_some() {
+            return Err("Impossible d'interrompre une table de tournoi".to_string());
+        }
+        if self.game_finished {
+            return Err("La partie est terminée".to_string());
+        }
+
+        self.aborted = true;
+        self.game_finished = true;
+        self.paused_at = None;
+
+        println!("🛑 {} interrompue par l'organisateur", self.name);
+        self.state.note("🛑 Game aborted");
+        self.publish(GameEvent::GameAborted { game_id: self.id });
+        self.publish(self.update_event());
+        Ok(())
+    }
+
+    /// Victoire par forfait : il ne reste qu'un joueur en lice
+    ///
+    /// En tournoi, c'est l'orchestrateur qui regroupe les tables et désigne le vainqueur.
+    fn check_forfeit_win(&mut self) {
+        if self.tournament_id.is_some() || self.cash.is_some() || self.game_finished {
+            return;
+        }
+
+        let mut contenders = self.state.players.iter()
+            .filter(|p| p.status != poker_engine::PlayerStatus::Eliminated)
+            .filter(|p| !self.finishing_positions.iter().any(|(id, _)| id == &p.id));
+
+        if let (Some(winner), None) = (contenders.next(), contenders.next()) {
+            let winner_id = winner.id.clone();
+            println!("🏆 Victoire par forfait de {}", winner.name);
+            self.game_finished = true;
+            self.record_finish(&winner_id, 1);
+            self.winner_id = Some(winner_id);
+        }
+    }
+
+    /// Exclure un joueur (organisateur)
+    ///
+    /// Avant le début de la partie, son siège est simplement libéré. En cours de partie, il
+    /// est couché immédiatement, classé à la prochaine place libre, et quitte la table à la
+    /// fin de la main.
+    pub fn kick_player(&mut self, player_id: &PlayerId) -> Result<(), String> {
+        let name = self.display_name(player_id);
+        self.withdraw_player(player_id)?;
+
+        println!("🚫 {} exclu de {} par l'organisateur", name, self.name);
+        self.state.note(format!("🚫 {} kicked", name));
+        self.publish_state_or_end();
+        Ok(())
+    }
+
+    /// Quitter la table
+    ///
+    /// Mêmes règles qu'une exclusion : en cours de partie, le joueur se couche, prend la
+    /// prochaine place libre du classement et emporte son tapis, retiré du jeu.
+    /// Retourne le nombre de jetons emportés.
+    pub fn leave(&mut self, player_id: &PlayerId) -> Result<u32, String> {
+        let all_in = self.state.find_player(player_id)
+            .is_some_and(|p| p.status == poker_engine::PlayerStatus::AllIn);
+        if all_in && self.state.is_hand_in_progress() {
+            return Err("Impossible de quitter la table à tapis : attendre la fin de la main".to_string());
+        }
+
+        let name = self.display_name(player_id);
+        let chips = self.withdraw_player(player_id)?;
+
+        println!("👋 {} quitte {} avec {} jetons", name, self.name, chips);
+        self.state.note(format!("👋 {} left the table ({} chips)", name, chips));
+        self.publish_state_or_end();
+        Ok(chips)
+    }
+
+    /// Retirer un joueur de la partie (exclusion ou départ), renvoie son tapis
+    fn withdraw_player(&mut self, player_id: &PlayerId) -> Result<u32, String> {
+        if self.tournament_id.is_some() {
+            return Err("Impossible de retirer un joueur d'une table de tournoi".to_string());
+        }
+        if self.duplicate_id.is_some() {
+            return Err("Impossible de retirer un joueur d'une table de match duplicate".to_string());
+        }
+        if self.game_finished {
+            return Err("La partie est terminée".to_string());
+        }
+        if self.finishing_positions.iter().any(|(id, _)| id == player_id) || !self.state.has_player(player_id) {
+            return Err("Ce joueur a déjà quitté la partie".to_string());
+        }
+        let chips = self.stack_of(player_id)?;
+
+        if !self.started {
+            self.state.remove_player(player_id).map_err(|e| e.to_string())?;
+            if self.cash.is_none() {
+                self.player_names.remove(player_id);
+                self.player_types.remove(player_id);
+                self.registered_bots.remove(player_id);
+            }
+        } else {
+            let previous_turn = self.current_player_id();
+            if self.cash.is_none() {
+                self.record_bust_out(player_id);
+            }
+            // Départ programmé avant de coucher le joueur : si cela termine la main, la
+            // suivante démarre sans lui
+            self.state.remove_player(player_id).map_err(|e| e.to_string())?;
+            self.state.force_fold(player_id).map_err(|e| e.to_string())?;
+            if self.current_player_id() != previous_turn {
+                self.last_action_time = std::time::SystemTime::now();
+            }
+            self.check_forfeit_win();
+        }
+        self.state.take_removed_players();
+        if let Some(cash) = self.cash.as_mut() {
+            cash.record_cash_out(player_id, chips);
+        }
+        Ok(chips)
+    }
+
+    /// Tapis d'un joueur, jetons achetés pendant la main compris
+    fn stack_of(&self, player_id: &PlayerId) -> Result<u32, String> {
+        let player = self.state.find_player(player_id)
+            .filter(|_| self.state.has_player(player_id))
+            .ok_or("Joueur non trouvé")?;
+        Ok(player.chips + self.state.pending_chips(player_id))
+    }
+
+    /// Recaver après avoir perdu son tapis (cash game)
+    pub fn rebuy(&mut self, player_id: &PlayerId, amount: u32) -> Result<u32, String> {
+        if self.stack_of(player_id)? > 0 {
+            return Err("Recave possible une fois le tapis perdu : utiliser le complément (top_up)".to_string());
+        }
+        self.buy_chips(player_id, amount)
+    }
+
+    /// Compléter son tapis (cash game), à hauteur de la cave maximale
+    pub fn top_up(&mut self, player_id: &PlayerId, amount: u32) -> Result<u32, String> {
+        if self.stack_of(player_id)? == 0 {
+            return Err("Tapis vide : utiliser la recave (rebuy)".to_string());
+        }
+        self.buy_chips(player_id, amount)
+    }
+
+    /// Acheter des jetons : ajoutés immédiatement entre deux mains, sinon à la fin de la main
+    fn buy_chips(&mut self, player_id: &PlayerId, amount: u32) -> Result<u32, String> {
+        if self.game_finished {
+            return Err("La partie est terminée".to_string());
+        }
+        let stack = self.stack_of(player_id)?;
+        let big_blind = self.state.big_blind;
+        let cash = self.cash.as_mut()
+            .ok_or("Réservé aux tables de cash game")?;
+        cash.check_buy_in(amount, stack, big_blind)?;
+
+        self.state.add_chips(player_id, amount).map_err(|e| e.to_string())?;
+        cash.record_buy_in(player_id, amount);
+
+        let name = self.display_name(player_id);
+        println!("💰 {} achète {} jetons à {} (tapis {})", name, amount, self.name, stack + amount);
+        self.state.note(format!("💰 {} buys {} chips", name, amount));
+        if !self.start_next_hand_if_ready() {
+            self.publish_state();
+        }
+        Ok(stack + amount)
+    }
+
+    /// S'absenter : plus servi ni redevable des blinds à partir de la prochaine main
+    pub fn sit_out(&mut self, player_id: &PlayerId) -> Result<(), String> {
+        self.check_can_sit_out()?;
+        self.state.sit_out(player_id).map_err(|e| e.to_string())?;
+
+        let name = self.display_name(player_id);
+        println!("💤 {} s'absente de {}", name, self.name);
+        self.state.note(format!("💤 {} sits out", name));
+        self.publish_state();
+        Ok(())
+    }
+
+    /// Revenir à la table après une absence
+    pub fn sit_in(&mut self, player_id: &PlayerId) -> Result<(), String> {
+        self.check_can_sit_out()?;
+        self.state.sit_in(player_id).map_err(|e| e.to_string())?;
+
+        let name = self.display_name(player_id);
+        println!("🔙 {} revient à {}", name, self.name);
+        self.state.note(format!("🔙 {} sits in", name));
+        if !self.start_next_hand_if_ready() {
+            self.publish_state();
+        }
+        Ok(())
+    }
+
+    fn check_can_sit_out(&self) -> Result<(), String> {
+        if self.tournament_id.is_some() {
+            return Err("Impossible de s'absenter d'une table de tournoi".to_string());
+        }
+        if self.duplicate_id.is_some() {
+            return Err("Impossible de s'absenter d'une table de match duplicate".to_string());
+        }
+        if self.game_finished {
+            return Err("La partie est terminée".to_string());
+        }
+        Ok(())
+    }
+
+    /// Relancer une table restée sans adversaire, une fois au moins deux joueurs disponibles
+    pub fn start_next_hand_if_ready(&mut self) -> bool {
+        if !self.started || self.game_finished || self.is_paused() || self.state.is_hand_in_progress() {
+            return false;
+        }
+        let available = self.state.players.iter().filter(|p| p.chips > 0 && !p.sitting_out).count();
+        if available < 2 {
+            return false;
+        }
+
+        self.state.start_new_hand();
+        self.last_action_time = std::time::SystemTime::now();
+        self.check_blind_level();
+        self.publish_state();
+        true
+    }
+
+    /// Diffuser le nouvel état, ou le vainqueur si la partie vient de se terminer
+    fn publish_state_or_end(&mut self) {
+        if self.game_finished {
+            self.publish_end();
+        } else {
+            self.publish_state();
         }
     }
 
     /// Vérifier les timeouts
     pub fn check_timeouts(&mut self) -> bool {
-        if !self.started || self.game_finished {
+        // Table en attente d'adversaires ou en pause : personne n'a la parole
+        if !self.started || self.game_finished || self.is_paused() || !self.state.is_hand_in_progress() {
             return false;
         }
 
@@ -254,53 +983,54 @@ impl GameRoom {
                 let strikes = self.player_timeout_strikes.entry(player_id.clone()).or_insert(0);
                 *strikes += 1;
                 
-                let is_ejected = *strikes >= 3;
+                // En cash game, le joueur n'est pas éjecté mais passe absent, avec son tapis
+                let is_ejected = *strikes >= 3 && self.cash.is_none();
+                let sits_out = *strikes >= 3 && self.cash.is_some();
                 
-                if is_ejected {
+                if sits_out {
+                    println!("💤 {} passe absent (3 timeouts consécutifs)", player_name);
+                    self.state.note(format!("💤 {} sits out (too many timeouts)", player_name));
+                    let _ = self.state.sit_out(&player_id);
+                    self.player_timeout_strikes.remove(&player_id);
+                } else if is_ejected {
                     println!("🚫 {} a été éjecté de la table (3 timeouts consécutifs)", player_name);
-                    self.action_log.push(format!("🚫 {} ejected (too many timeouts)", player_name));
-                    
-                    // Marquer comme SittingOut ou Eliminated dans le moteur pour qu'il soit ignoré
-                    self.state.players[current_player_idx].status = poker_engine::PlayerStatus::Eliminated;
+                    self.state.note(format!("🚫 {} ejected (too many timeouts)", player_name));
+                    // Le joueur éjecté perd son tapis : s'il met fin à la main, la suivante
+                    // démarre sans lui
+                    self.state.players[current_player_idx].chips = 0;
                 } else {
                     println!("⚠️ {} fold automatiquement (Strike {}/3)", player_name, strikes);
-                    self.action_log.push(format!("⏰ {} timeout (fold) [{}/3]", player_name, strikes));
+                    self.state.note(format!("⏰ {} timeout (fold) [{}/3]", player_name, strikes));
                 }
 
-                // Forcer le Fold (ou juste passer le tour si éjecté)
-                // On utilise execute_action avec Fold. Si déjà éliminé, le moteur pourrait rejeter,
-                // mais on a besoin de faire avancer le jeu.
-                // Si on l'a marqué éliminé manuellement, il faut quand même faire avancer le `current_player`.
-                
                 // SÉCURITÉ: On reset le timestamp pour ne pas boucler infiniment si ça plante
                 self.last_action_time = std::time::SystemTime::now();
 
-                // Tentative de Fold propre via le moteur
-                // Si le joueur est ejected, le Fold est technique pour passer au suivant.
-                match self.state.execute_action(&player_id, poker_engine::PlayerAction::Fold) {
+                // Coucher le joueur via le moteur, qui passe la parole au suivant
+                match self.state.force_fold(&player_id) {
                     Ok(_) => {
                         println!("✅ Auto-fold exécuté avec succès");
-                        
-                        // Si éjecté, s'assurer qu'il reste Eliminated (le moteur l'a peut-être mis Folded)
+
+                        // Une fois couché, l'éjecté est écarté des mains suivantes
                         if is_ejected {
-                             self.state.players[current_player_idx].status = poker_engine::PlayerStatus::Eliminated;
+                            if let Some(player) = self.state.players.iter_mut().find(|p| p.id == player_id) {
+                                player.status = poker_engine::PlayerStatus::Eliminated;
+                            }
+                            if self.tournament_id.is_none() {
+                                self.record_bust_out(&player_id);
+                            }
                         }
 
                         // Vérifier victoire par forfait
-                        let active_count = self.state.players.iter()
-                            .filter(|p| p.status != poker_engine::PlayerStatus::Eliminated && p.status != poker_engine::PlayerStatus::SittingOut)
-                            .count();
-                            
-                        if active_count <= 1 {
-                             let winner = self.state.players.iter()
-                                .find(|p| p.status != poker_engine::PlayerStatus::Eliminated && p.status != poker_engine::PlayerStatus::SittingOut);
-                                
-                             if let Some(w) = winner {
-                                 self.game_finished = true;
-                                 self.winner_id = Some(w.id.clone());
-                                 println!("🏆 Victoire par forfait de {}", w.name);
-                             }
-                        }
+                        self.check_forfeit_win();
+
+                        self.publish(GameEvent::PlayerAction {
+                            game_id: self.id,
+                            player_name,
+                            action: "fold".to_string(),
+                            amount: None,
+                        });
+                        self.publish_state_or_end();
                     },
                     Err(e) => {
                          println!("❌ Erreur lors de l'auto-fold: {}", e);
@@ -317,30 +1047,172 @@ impl GameRoom {
     }
 }
 
-/// Gestionnaire de toutes les parties
-pub struct GameManager {
-    games: Arc<Mutex<HashMap<GameId, GameRoom>>>,
+/// Erreur lors de la soumission d'une action
+#[derive(Debug)]
+pub enum ActionError {
+    Rejected(GameError), // Action refusée par le moteur
+    Request(String),     // Token invalide, partie introuvable ou terminée...
 }
 
-impl GameManager {
-    pub fn new() -> Self {
-        GameManager {
-            games: Arc::new(Mutex::new(HashMap::new())),
-        }
+impl From<String> for ActionError {
+    fn from(message: String) -> Self {
+        ActionError::Request(message)
+    }
+}
+
+impl From<&str> for ActionError {
+    fn from(message: &str) -> Self {
+        ActionError::Request(message.to_string())
+    }
+}
+
+/// Durée par défaut avant l'archivage automatique d'une partie terminée (1 heure)
+pub const DEFAULT_FINISHED_GAME_TTL: std::time::Duration = std::time::Duration::from_secs(3600);
+
+/// Gestionnaire de toutes les parties
+pub struct GameManager {
+    games: Arc<Mutex<HashMap<GameId, GameRoom>>>,
+    tournaments: Arc<Mutex<HashMap<TournamentId, Tournament>>>, // Verrouiller avant `games`
+    duplicates: Arc<Mutex<HashMap<DuplicateId, DuplicateMatch>>>, // Verrouiller après `tournaments`, avant `games`
+    archive: Arc<Mutex<Vec<ArchivedGame>>>, // Verrouiller après `games`
+    bots: Arc<Mutex<BotRegistry>>,
+    finished_game_ttl: Option<std::time::Duration>, // None : pas d'archivage automatique
+    storage: Option<Storage>, // None : rien n'est conservé après un redémarrage
+}
+
+impl GameManager {
+    pub fn new() -> Self {
+        Self::with_finished_game_ttl(Some(DEFAULT_FINISHED_GAME_TTL))
+    }
+
+    /// Gestionnaire archivant les parties terminées depuis plus de `ttl`
+    pub fn with_finished_game_ttl(ttl: Option<std::time::Duration>) -> Self {
+        GameManager {
+            games: Arc::new(Mutex::new(HashMap::new())),
+            tournaments: Arc::new(Mutex::new(HashMap::new())),
+            duplicates: Arc::new(Mutex::new(HashMap::new())),
+            archive: Arc::new(Mutex::new(Vec::new())),
+            bots: Arc::new(Mutex::new(BotRegistry::new())),
+            finished_game_ttl: ttl,
+            storage: None,
+        }
+    }
+
+    /// Enregistrer parties, tournois et bots dans `storage`, en reprenant ce qu'il contient déjà
+    pub fn with_storage(mut self, storage: Storage) -> Result<Self, String> {
+        let saved = storage.load()?;
+        println!("💾 Restauration: {} parties, {} tournois, {} matchs duplicate, {} bots, {} parties archivées",
+            saved.games.len(), saved.tournaments.len(), saved.duplicates.len(), saved.bots.len(), saved.archive.len());
+
+        {
+            let mut bots = self.bots.lock().unwrap();
+            for account in saved.bots {
+                bots.insert(account);
+            }
+        }
+        self.tournaments.lock().unwrap().extend(saved.tournaments.into_iter().map(|t| (t.id, t)));
+        self.duplicates.lock().unwrap().extend(saved.duplicates.into_iter().map(|d| (d.id, d)));
+        self.games.lock().unwrap().extend(saved.games.into_iter().map(|game| (game.id, game)));
+        self.archive.lock().unwrap().extend(saved.archive);
+
+        self.storage = Some(storage);
+        Ok(self)
+    }
+
+    /// Enregistrer un bot : identifiant stable et clé d'API pour rejoindre les parties
+    pub fn register_bot(&self, req: RegisterBotRequest) -> Result<RegisterBotResponse, String> {
+        let mut bots = self.bots.lock().unwrap();
+        let (account, api_key) = bots.register(&req.name)?;
+        if let Some(storage) = &self.storage {
+            // Un compte non enregistré serait perdu au redémarrage, avec sa clé
+            if let Err(e) = storage.save_bot(&account) {
+                bots.remove(&account.bot_id);
+                return Err(e);
+            }
+        }
+        Ok(RegisterBotResponse {
+            bot_id: account.bot_id,
+            name: account.name,
+            api_key,
+        })
+    }
+
+    /// Profil d'un bot enregistré et ses résultats au classement général
+    pub fn get_bot(&self, bot_id: &str) -> Result<BotProfileResponse, String> {
+        let account = self.bots.lock().unwrap().get(bot_id)
+            .cloned()
+            .ok_or("Bot inconnu")?;
+
+        let stats = self.get_leaderboard().entries.into_iter()
+            .find(|entry| entry.bot_id.as_deref() == Some(bot_id));
+
+        Ok(BotProfileResponse {
+            bot_id: account.bot_id,
+            name: account.name,
+            created_at: account.created_at,
+            stats,
+        })
+    }
+
+    /// Identité d'un joueur qui rejoint une partie ou un tournoi : (player_id, nom, bot_id)
+    ///
+    /// Un bot enregistré s'authentifie avec `bot_id` et sa clé d'API (`bot_secret`) et garde
+    /// le même identifiant partout. Sans compte, le joueur est un invité, qui ne peut pas
+    /// prendre le nom d'un bot enregistré.
+    fn resolve_player(&self, req: &JoinGameRequest) -> Result<(PlayerId, String, Option<BotId>), String> {
+        let bots = self.bots.lock().unwrap();
+
+        if let Some(bot_id) = &req.bot_id {
+            let api_key = req.bot_secret.as_deref()
+                .ok_or("bot_secret requis pour un bot enregistré")?;
+            let account = bots.authenticate(bot_id, api_key)?;
+            return Ok((account.bot_id.clone(), account.name.clone(), Some(account.bot_id.clone())));
+        }
+
+        let name = req.bot_name.trim();
+        if name.is_empty() {
+            return Err("bot_name requis".to_string());
+        }
+        if bots.is_registered_name(name) {
+            return Err(format!("{} est un bot enregistré : bot_id et bot_secret requis", name));
+        }
+        Ok((format!("{}_{}", name, Uuid::new_v4()), name.to_string(), None))
     }
 
     /// Créer une nouvelle partie
     pub fn create_game(&self, req: CreateGameRequest) -> Result<CreateGameResponse, String> {
         let game_id = Uuid::new_v4();
-        let game_room = GameRoom::new(
+        let mut game_room = GameRoom::new(
             game_id,
             req.name.clone(),
             req.max_players,
             req.starting_chips,
-            req.small_blind,
-            req.big_blind,
+            BlindsConfig {
+                small_blind: req.small_blind,
+                big_blind: req.big_blind,
+                ante: req.ante,
+                big_blind_ante: req.big_blind_ante,
+                straddle: req.straddle,
+            },
         );
 
+        game_room.state.odd_chip_rule = req.odd_chip_rule;
+        game_room.state.seed = req.seed;
+        game_room.state.missed_blinds_rule = req.missed_blinds;
+        game_room.late_join = req.late_join;
+        if let Some(config) = req.cash {
+            if !req.blind_levels.is_empty() {
+                return Err("Pas de structure de blinds en cash game".to_string());
+            }
+            let cash = CashTable::new(config.min_buy_in_bb, config.max_buy_in_bb)?;
+            cash.check_buy_in(req.starting_chips, 0, req.big_blind)
+                .map_err(|e| format!("starting_chips (cave par défaut) invalide : {}", e))?;
+            game_room.cash = Some(cash);
+            game_room.late_join = true;
+        }
+        game_room.set_blind_levels(req.blind_levels);
+        game_room.points_table = req.points_table;
+
         let mut games = self.games.lock().unwrap();
         games.insert(game_id, game_room);
 
@@ -352,18 +1224,21 @@ impl GameManager {
 
     /// Rejoindre une partie
     pub fn join_game(&self, game_id: GameId, req: JoinGameRequest) -> Result<JoinGameResponse, String> {
+        let (player_id, display_name, bot_id) = self.resolve_player(&req)?;
+
         let mut games = self.games.lock().unwrap();
         let game = games.get_mut(&game_id)
             .ok_or("Partie non trouvée")?;
 
-        // Utiliser un nom différent selon le type de joueur
-        let display_name = match req.player_type {
-            PlayerType::Human => req.bot_name.clone(),
-            PlayerType::Bot => req.bot_name.clone(),
-        };
-
-        let player_id = format!("{}_{}", req.bot_name, Uuid::new_v4());
-        let position = game.add_player(player_id.clone(), display_name, req.player_type)?;
+        let position = game.add_player(player_id.clone(), display_name.clone(), req.player_type, req.buy_in)?;
+        if bot_id.is_some() {
+            game.registered_bots.insert(player_id.clone());
+        }
+        game.publish(GameEvent::PlayerJoined {
+            game_id,
+            player_name: display_name,
+        });
+        game.start_next_hand_if_ready();
 
         // Générer un token JWT pour ce joueur
         let auth_token = create_token(&player_id, &game_id.to_string())
@@ -378,26 +1253,36 @@ impl GameManager {
     }
 
     /// Soumettre une action
-    pub fn submit_action(&self, game_id: GameId, req: SubmitActionRequest) -> Result<SubmitActionResponse, String> {
-        // SÉCURITÉ: Vérifier le token JWT et extraire le player_id
-        let claims = crate::auth::verify_token(&req.auth_token)?;
-        
-        if claims.game_id != game_id.to_string() {
-            return Err("Token invalide pour cette partie".to_string());
-        }
-        
-        let player_id = claims.player_id;
-        
+    pub fn submit_action(&self, game_id: GameId, req: SubmitActionRequest) -> Result<SubmitActionResponse, ActionError> {
+        let response = self.apply_action(game_id, req)?;
+
+        // Table de tournoi : éliminations, équilibrage et cassage des tables entre les mains
+        self.update_tournament_of(game_id);
+        // Table de match duplicate : table suivante une fois toutes les mains jouées
+        self.update_duplicate_of(game_id);
+
+        Ok(response)
+    }
+
+    /// Exécuter l'action d'un joueur sur une table
+    fn apply_action(&self, game_id: GameId, req: SubmitActionRequest) -> Result<SubmitActionResponse, ActionError> {
         let mut games = self.games.lock().unwrap();
         let game = games.get_mut(&game_id)
             .ok_or("Partie non trouvée")?;
 
+        // SÉCURITÉ: Vérifier le token JWT et extraire le player_id
+        let player_id = game.authenticate(&req.auth_token)?;
+
         if !game.started {
-            return Err("La partie n'a pas encore commencé".to_string());
+            return Err("La partie n'a pas encore commencé".into());
         }
 
         if game.game_finished {
-            return Err("La partie est terminée".to_string());
+            return Err("La partie est terminée".into());
+        }
+
+        if game.is_paused() {
+            return Err("La partie est en pause".into());
         }
 
         let player_name = game.player_names.get(&player_id)
@@ -424,7 +1309,6 @@ impl GameManager {
             player_name,
             action_desc
         );
-        game.action_log.push(log_entry.clone());
         
         // LOG: État avant l'action
         println!("🎲 État: Phase={}, Pot={}, CurrentBet={}, CurrentPlayer={}", 
@@ -434,9 +1318,22 @@ impl GameManager {
             game.state.current_player
         );
 
-        // Capturer le pot AVANT le showdown (car il sera distribué après)
-        let pot_before_action = game.state.pot;
-        let was_river_phase = game.state.phase == poker_engine::GamePhase::River;
+        // Événement diffusé si l'action est acceptée, avec le montant engagé
+        let amount = game.state.players.iter().find(|p| p.id == player_id).and_then(|p| match &engine_action {
+            poker_engine::PlayerAction::Call => Some(game.state.current_bet.saturating_sub(p.current_bet).min(p.chips)),
+            poker_engine::PlayerAction::Raise(amount) => Some(*amount),
+            poker_engine::PlayerAction::AllIn => Some(p.chips),
+            _ => None,
+        });
+        let action_event = GameEvent::PlayerAction {
+            game_id,
+            player_name: player_name.clone(),
+            action: action_to_string(&engine_action),
+            amount,
+        };
+
+        // Le moteur enchaîne automatiquement sur la main suivante : on détecte la fin de main au numéro
+        let hand_before = game.state.hand_number;
 
         match game.state.execute_action(&player_id, engine_action) {
             Ok(_) => {
@@ -446,100 +1343,78 @@ impl GameManager {
 
                 // LOG: Action exécutée avec succès
                 println!("✅ {} | Pot maintenant: {}", log_entry, game.state.pot);
+                game.publish(action_event);
+
+                game.check_blind_level();
+                game.record_busts();
+
+                if game.state.hand_number != hand_before {
+                    if let Some(result) = &game.state.last_hand_result {
+                        for winner in result.winnings() {
+                            println!("🎊 Main terminée - {} gagne {} jetons ({})",
+                                game.display_name(&winner.player_id),
+                                winner.amount,
+                                result.description()
+                            );
+                        }
+                    }
+                    println!("🆕 Nouvelle main: Phase={}, Pot={}, Dealer={}",
+                        phase_to_string(&game.state.phase),
+                        game.state.pot,
+                        game.state.dealer_position
+                    );
+                }
                 
                 // LOG: Nouveau joueur actif
-                let next_player = &game.state.players[game.state.current_player];
-                let next_player_name = game.player_names.get(&next_player.id)
-                    .cloned()
-                    .unwrap_or_else(|| next_player.id.clone());
-                println!("👉 Tour suivant: {} (Phase: {})", 
-                    next_player_name,
-                    phase_to_string(&game.state.phase)
-                );
+                if let Some(next_player) = game.state.players.get(game.state.current_player) {
+                    println!("👉 Tour suivant: {} (Phase: {})", 
+                        game.display_name(&next_player.id),
+                        phase_to_string(&game.state.phase)
+                    );
+                }
                 
                 // Vérifier si un seul joueur a encore des jetons
+                // (en tournoi, le joueur restant attend d'être déplacé vers une autre table)
                 let players_with_chips: Vec<_> = game.state.players.iter()
                     .filter(|p| p.chips > 0)
                     .collect();
                 
-                if players_with_chips.len() == 1 {
+                if players_with_chips.len() == 1 && game.tournament_id.is_none() && game.cash.is_none() {
+                    let winner_id = players_with_chips[0].id.clone();
                     game.game_finished = true;
-                    game.winner_id = Some(players_with_chips[0].id.clone());
+                    game.record_finish(&winner_id, 1);
+                    game.winner_id = Some(winner_id.clone());
                     
                     // Afficher le log complet de la partie
                     println!("\n════════════════════════════════════════");
                     println!("🏆 PARTIE TERMINÉE - LOG COMPLET");
                     println!("════════════════════════════════════════");
-                    println!("Gagnant: {}", game.player_names.get(&players_with_chips[0].id)
-                        .unwrap_or(&players_with_chips[0].id));
+                    println!("Gagnant: {}", game.display_name(&winner_id));
                     println!("\n📋 Historique des actions:\n");
-                    for (i, entry) in game.action_log.iter().enumerate() {
+                    for (i, entry) in game.log_lines(usize::MAX).iter().enumerate() {
                         println!("  {}. {}", i + 1, entry);
                     }
                     println!("\n════════════════════════════════════════\n");
-                    
+
+                    game.publish_end();
                     return Ok(SubmitActionResponse {
                         success: true,
                         error: None,
+                        error_code: None,
                     });
                 }
-                
-                // Si on vient de passer en showdown depuis River, capturer le gagnant
-                if was_river_phase && game.state.phase == poker_engine::GamePhase::Showdown {
-                    // Trouver le gagnant en comparant les jetons avant/après
-                    let active_players: Vec<_> = game.state.players.iter()
-                        .filter(|p| p.status != poker_engine::PlayerStatus::Folded)
-                        .collect();
-                    
-                    if !active_players.is_empty() {
-                        // Trouver qui a gagné le pot (celui qui a le plus de jetons maintenant)
-                        // Note: pot_before_action contient le montant qui sera distribué
-                        if let Some(winner) = active_players.iter().max_by_key(|p| p.chips) {
-                            game.last_hand_winner = Some(winner.id.clone());
-                            game.last_hand_amount = Some(pot_before_action);
-                            game.last_hand_description = Some(format!("Main gagnante"));
-                            // Capturer les cartes du gagnant
-                            game.last_hand_cards = Some(
-                                winner.hole_cards.iter().map(card_to_string).collect()
-                            );
-                            
-                            println!("🎊 SHOWDOWN - {} gagne {} jetons avec {:?}", 
-                                game.player_names.get(&winner.id).unwrap_or(&winner.id),
-                                pot_before_action,
-                                winner.hole_cards
-                            );
-                        }
-                    }
-                    
-                    println!("🎊 SHOWDOWN terminé - Nouvelle main commence dans 5 secondes");
-                    game.action_log.push(format!("[SHOWDOWN] Nouveau tour commence"));
-                    
-                    // Démarrer la nouvelle main
-                    game.state.start_new_hand();
-                    
-                    // Réinitialiser les infos de la dernière main après 1 cycle
-                    // (elles seront affichées pendant 5s côté client puis effacées)
-                    
-                    // LOG: État après nouvelle main
-                    println!("🆕 Nouvelle main: Phase={}, Pot={}, Dealer={}", 
-                        phase_to_string(&game.state.phase),
-                        game.state.pot,
-                        game.state.dealer_position
-                    );
-                }
-                
+
+                game.publish_state();
                 Ok(SubmitActionResponse {
                     success: true,
                     error: None,
+                    error_code: None,
                 })
             },
             Err(e) => {
                 // LOG: Erreur
                 println!("❌ Action refusée de {}: {}", player_name, e);
-                Ok(SubmitActionResponse {
-                    success: false,
-                    error: Some(e),
-                })
+                Err(ActionError::Rejected(e))
             },
         }
     }
@@ -551,7 +1426,130 @@ impl GameManager {
         let mut games = self.games.lock().unwrap();
         let game = games.get_mut(&game_id)
             .ok_or("Partie non trouvée")?;
-        game.start_game()
+        game.start_game()?;
+
+        game.publish(GameEvent::GameStarted { game_id });
+        game.publish_state();
+        Ok(())
+    }
+
+    /// S'abonner aux événements d'une partie (WebSocket, SSE)
+    ///
+    /// Avec `last_event_id`, les événements manqués depuis sont rejoués s'ils sont encore
+    /// dans l'historique. Sinon, le backlog contient l'état actuel (dont "à vous de jouer"
+    /// si le joueur a déjà la parole).
+    pub fn subscribe(&self, game_id: GameId, token: &str, last_event_id: Option<u64>) -> Result<Subscription, String> {
+        let games = self.games.lock().unwrap();
+        let game = games.get(&game_id)
+            .ok_or("Partie non trouvée")?;
+        let player_id = game.authenticate(token)?;
+
+        let missed = last_event_id.and_then(|id| game.events_since(id, &player_id));
+        let backlog = missed.unwrap_or_else(|| {
+            std::iter::once(game.update_event())
+                .chain(game.turn_event())
+                .filter(|event| event.is_visible_to(&player_id))
+                .map(|event| SequencedEvent { id: game.last_event_id, event })
+                .collect()
+        });
+
+        Ok(Subscription {
+            player_id,
+            events: game.events.subscribe(),
+            backlog,
+        })
+    }
+
+    /// Attendre le tour d'un joueur (long-poll)
+    ///
+    /// Rend la main dès que le joueur doit agir, que la main en cours se termine, que la
+    /// partie s'achève ou que le délai expire. Chaque changement publié sur la table réveille
+    /// l'attente, sans interroger l'état en boucle.
+    pub async fn wait_for_turn(&self, game_id: GameId, player_id: &PlayerId, timeout: std::time::Duration) -> Result<WaitResponse, String> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let (mut changes, hand_number) = {
+            let games = self.games.lock().unwrap();
+            let game = games.get(&game_id)
+                .ok_or("Partie non trouvée")?;
+            (game.changes.subscribe(), game.state.hand_number)
+        };
+
+        loop {
+            {
+                let games = self.games.lock().unwrap();
+                let game = games.get(&game_id)
+                    .ok_or("Partie non trouvée")?;
+
+                let is_my_turn = matches!(
+                    game.turn_event(),
+                    Some(GameEvent::YourTurn { player_id: ref current, .. }) if current == player_id
+                );
+                let reason = if game.game_finished {
+                    Some(WaitReason::GameFinished)
+                } else if is_my_turn {
+                    Some(WaitReason::YourTurn)
+                } else if game.state.hand_number != hand_number {
+                    Some(WaitReason::HandEnded)
+                } else {
+                    None
+                };
+
+                if let Some(reason) = reason {
+                    return Ok(WaitResponse { reason, state: game.get_state_for_player(player_id) });
+                }
+            }
+
+            match tokio::time::timeout_at(deadline, changes.changed()).await {
+                Ok(Ok(())) => continue,
+                // Délai écoulé (ou partie supprimée) : état actuel
+                Ok(Err(_)) | Err(_) => {
+                    let state = self.get_game_state(game_id, player_id)?;
+                    return Ok(WaitResponse { reason: WaitReason::Timeout, state });
+                }
+            }
+        }
+    }
+
+    /// Identifier le joueur porteur d'un token pour une partie (ou une table de son tournoi)
+    pub fn authenticate(&self, game_id: GameId, token: &str) -> Result<PlayerId, String> {
+        let games = self.games.lock().unwrap();
+        let game = games.get(&game_id)
+            .ok_or("Partie non trouvée")?;
+        game.authenticate(token)
+    }
+
+    /// Renouveler le token d'un joueur sans qu'il ait à rejoindre à nouveau
+    ///
+    /// Le token présenté doit être encore valide, et le joueur toujours inscrit à la partie
+    /// ou au tournoi pour lequel il a été émis.
+    pub fn refresh_token(&self, token: &str) -> Result<TokenResponse, String> {
+        let claims = crate::auth::verify_token(token)?;
+        if claims.role == Role::Admin {
+            let auth_token = crate::auth::config().create_admin_token()
+                .map_err(|e| format!("Erreur de génération de token: {}", e))?;
+            return TokenResponse::new(auth_token);
+        }
+
+        let scope_id: Uuid = claims.game_id.parse()
+            .map_err(|_| "Token invalide".to_string())?;
+
+        let entrants = match self.tournaments.lock().unwrap().get(&scope_id) {
+            Some(tournament) => Some(tournament.entrants.clone()),
+            None => self.duplicates.lock().unwrap().get(&scope_id).map(|duplicate| duplicate.entrants.clone()),
+        };
+        let registered = match entrants {
+            Some(entrants) => entrants.iter().any(|e| e.player_id == claims.player_id),
+            None => self.games.lock().unwrap().get(&scope_id)
+                .ok_or("Partie non trouvée")?
+                .player_names.contains_key(&claims.player_id),
+        };
+        if !registered {
+            return Err("Joueur inconnu pour ce token".to_string());
+        }
+
+        let auth_token = create_token(&claims.player_id, &claims.game_id)
+            .map_err(|e| format!("Erreur de génération de token: {}", e))?;
+        TokenResponse::new(auth_token)
     }
 
     /// Obtenir l'état d'une partie pour un joueur
@@ -562,6 +1560,167 @@ impl GameManager {
         Ok(game.get_state_for_player(player_id))
     }
 
+    /// Obtenir l'état public d'une partie (spectateur, sans aucune carte privée)
+    pub fn get_spectator_state(&self, game_id: GameId) -> Result<GameStateResponse, String> {
+        let games = self.games.lock().unwrap();
+        let game = games.get(&game_id)
+            .ok_or("Partie non trouvée")?;
+        Ok(game.get_state_for_viewer(Viewer::Spectator))
+    }
+
+    /// Obtenir l'état complet d'une partie, toutes cartes visibles (organisateur)
+    pub fn get_admin_state(&self, game_id: GameId) -> Result<GameStateResponse, String> {
+        let games = self.games.lock().unwrap();
+        let game = games.get(&game_id)
+            .ok_or("Partie non trouvée")?;
+        Ok(game.get_state_for_viewer(Viewer::Admin))
+    }
+
+    /// Historique d'une main terminée au format PokerStars, vu par `viewer`
+    pub fn get_hand_history(&self, game_id: GameId, hand_number: u32, viewer: Viewer) -> Result<String, String> {
+        let games = self.games.lock().unwrap();
+        let game = games.get(&game_id)
+            .ok_or("Partie non trouvée")?;
+        let hand = game.hand_history.iter()
+            .find(|hand| hand.hand_number == hand_number)
+            .ok_or("Main non trouvée")?;
+        Ok(hand_history::to_pokerstars(&game.name, game.max_players, hand, viewer))
+    }
+
+    /// Exclure un joueur d'une partie (organisateur)
+    pub fn kick_player(&self, game_id: GameId, player_id: &PlayerId) -> Result<(), String> {
+        let mut games = self.games.lock().unwrap();
+        let game = games.get_mut(&game_id)
+            .ok_or("Partie non trouvée")?;
+        game.kick_player(player_id)
+    }
+
+    /// S'absenter de la table
+    pub fn sit_out(&self, game_id: GameId, player_id: &PlayerId) -> Result<(), String> {
+        let mut games = self.games.lock().unwrap();
+        let game = games.get_mut(&game_id)
+            .ok_or("Partie non trouvée")?;
+        game.sit_out(player_id)
+    }
+
+    /// Revenir à la table
+    pub fn sit_in(&self, game_id: GameId, player_id: &PlayerId) -> Result<(), String> {
+        let mut games = self.games.lock().unwrap();
+        let game = games.get_mut(&game_id)
+            .ok_or("Partie non trouvée")?;
+        game.sit_in(player_id)
+    }
+
+    /// Recaver après avoir perdu son tapis (cash game)
+    pub fn rebuy(&self, game_id: GameId, player_id: &PlayerId, amount: u32) -> Result<BuyChipsResponse, String> {
+        let mut games = self.games.lock().unwrap();
+        let game = games.get_mut(&game_id)
+            .ok_or("Partie non trouvée")?;
+        let chips = game.rebuy(player_id, amount)?;
+        Ok(BuyChipsResponse { chips })
+    }
+
+    /// Compléter son tapis (cash game)
+    pub fn top_up(&self, game_id: GameId, player_id: &PlayerId, amount: u32) -> Result<BuyChipsResponse, String> {
+        let mut games = self.games.lock().unwrap();
+        let game = games.get_mut(&game_id)
+            .ok_or("Partie non trouvée")?;
+        let chips = game.top_up(player_id, amount)?;
+        Ok(BuyChipsResponse { chips })
+    }
+
+    /// Quitter la table avec son tapis
+    pub fn leave_game(&self, game_id: GameId, player_id: &PlayerId) -> Result<LeaveGameResponse, String> {
+        let mut games = self.games.lock().unwrap();
+        let game = games.get_mut(&game_id)
+            .ok_or("Partie non trouvée")?;
+        let chips = game.leave(player_id)?;
+        Ok(LeaveGameResponse { chips })
+    }
+
+    /// Suspendre une partie (organisateur)
+    pub fn pause_game(&self, game_id: GameId) -> Result<(), String> {
+        let mut games = self.games.lock().unwrap();
+        let game = games.get_mut(&game_id)
+            .ok_or("Partie non trouvée")?;
+        game.pause()
+    }
+
+    /// Reprendre une partie suspendue (organisateur)
+    pub fn resume_game(&self, game_id: GameId) -> Result<(), String> {
+        {
+            let mut games = self.games.lock().unwrap();
+            let game = games.get_mut(&game_id)
+                .ok_or("Partie non trouvée")?;
+            game.resume()?;
+        }
+
+        // Table de tournoi mise en pause entre deux mains : relancer la main suivante
+        self.update_tournament_of(game_id);
+        Ok(())
+    }
+
+    /// Interrompre une partie, jetons figés (organisateur)
+    pub fn abort_game(&self, game_id: GameId) -> Result<(), String> {
+        let mut games = self.games.lock().unwrap();
+        let game = games.get_mut(&game_id)
+            .ok_or("Partie non trouvée")?;
+        game.abort()
+    }
+
+    /// Archiver une partie terminée : la salle est libérée, ses résultats restent au classement
+    pub fn archive_game(&self, game_id: GameId) -> Result<ArchivedGame, String> {
+        let mut games = self.games.lock().unwrap();
+        let game = games.get(&game_id)
+            .ok_or("Partie non trouvée")?;
+        if !game.game_finished {
+            return Err("Seule une partie terminée peut être archivée".to_string());
+        }
+
+        if let Some(storage) = &self.storage {
+            storage.archive_game(&game.archive())?;
+        }
+        let archived = games.remove(&game_id).map(|game| game.archive()).unwrap();
+        println!("🗄️  Partie archivée: {}", archived.name);
+        self.archive.lock().unwrap().push(archived.clone());
+        Ok(archived)
+    }
+
+    /// Supprimer une partie, ou une partie archivée, sans rien en conserver
+    ///
+    /// Une partie en cours doit d'abord être interrompue.
+    pub fn delete_game(&self, game_id: GameId) -> Result<(), String> {
+        let mut games = self.games.lock().unwrap();
+        if let Some(game) = games.get(&game_id) {
+            if game.started && !game.game_finished {
+                return Err("Partie en cours : l'interrompre avant de la supprimer".to_string());
+            }
+            if let Some(storage) = &self.storage {
+                storage.delete_game(game_id)?;
+            }
+            let game = games.remove(&game_id).unwrap();
+            println!("🗑️  Partie supprimée: {}", game.name);
+            return Ok(());
+        }
+
+        let mut archive = self.archive.lock().unwrap();
+        let index = archive.iter().position(|archived| archived.game_id == game_id)
+            .ok_or("Partie non trouvée")?;
+        if let Some(storage) = &self.storage {
+            storage.delete_game(game_id)?;
+        }
+        let archived = archive.remove(index);
+        println!("🗑️  Partie archivée supprimée: {}", archived.name);
+        Ok(())
+    }
+
+    /// Lister les parties archivées
+    pub fn list_archived_games(&self) -> ArchivedGameListResponse {
+        ArchivedGameListResponse {
+            games: self.archive.lock().unwrap().clone(),
+        }
+    }
+
     /// Lister toutes les parties
     pub fn list_games(&self) -> GameListResponse {
         let games = self.games.lock().unwrap();
@@ -574,18 +1733,299 @@ impl GameManager {
         }
     }
 
-    /// Obtenir une copie d'une partie (pour WebSocket)
-    #[allow(dead_code)] // Pour usage futur (WebSocket)
+    /// Obtenir une copie d'une partie (tests de la persistance)
+    #[cfg(test)]
     pub fn get_game(&self, game_id: GameId) -> Option<GameRoom> {
         let games = self.games.lock().unwrap();
         games.get(&game_id).cloned()
     }
 
-    /// Maintenance périodique (timeouts)
+    /// Créer un tournoi multi-tables
+    pub fn create_tournament(&self, req: CreateTournamentRequest) -> Result<CreateTournamentResponse, String> {
+        if req.table_size < 2 {
+            return Err("Une table doit accueillir au moins 2 joueurs".to_string());
+        }
+
+        let tournament_id = Uuid::new_v4();
+        let tournament = Tournament::new(
+            tournament_id,
+            req.name.clone(),
+            req.table_size,
+            req.starting_chips,
+            BlindsConfig {
+                ante: req.ante,
+                ..BlindsConfig::new(req.small_blind, req.big_blind)
+            },
+            req.blind_levels,
+            req.points_table,
+        );
+
+        let mut tournaments = self.tournaments.lock().unwrap();
+        tournaments.insert(tournament_id, tournament);
+
+        Ok(CreateTournamentResponse {
+            tournament_id,
+            name: req.name,
+        })
+    }
+
+    /// S'inscrire à un tournoi
+    pub fn register_tournament(&self, tournament_id: TournamentId, req: JoinGameRequest) -> Result<TournamentRegistrationResponse, String> {
+        if req.buy_in.is_some() {
+            return Err("buy_in est réservé aux tables de cash game".to_string());
+        }
+        let (player_id, name, bot_id) = self.resolve_player(&req)?;
+
+        let mut tournaments = self.tournaments.lock().unwrap();
+        let tournament = tournaments.get_mut(&tournament_id)
+            .ok_or("Tournoi non trouvé")?;
+
+        tournament.register(Entrant {
+            player_id: player_id.clone(),
+            name,
+            player_type: req.player_type,
+            bot_id,
+        })?;
+
+        // Le token est lié au tournoi : il reste valable après un changement de table
+        let auth_token = create_token(&player_id, &tournament_id.to_string())
+            .map_err(|e| format!("Erreur de génération de token: {}", e))?;
+
+        Ok(TournamentRegistrationResponse {
+            player_id,
+            tournament_id,
+            auth_token,
+        })
+    }
+
+    /// Démarrer un tournoi : création des tables et placement des joueurs
+    pub fn start_tournament(&self, tournament_id: TournamentId) -> Result<(), String> {
+        let mut tournaments = self.tournaments.lock().unwrap();
+        let tournament = tournaments.get_mut(&tournament_id)
+            .ok_or("Tournoi non trouvé")?;
+
+        let mut games = self.games.lock().unwrap();
+        tournament.start(&mut games)
+    }
+
+    /// Obtenir l'état d'un tournoi (tables, joueurs restants, classement)
+    pub fn get_tournament(&self, tournament_id: TournamentId) -> Result<TournamentStatusResponse, String> {
+        let tournaments = self.tournaments.lock().unwrap();
+        let tournament = tournaments.get(&tournament_id)
+            .ok_or("Tournoi non trouvé")?;
+
+        let games = self.games.lock().unwrap();
+        Ok(tournament.status(&games))
+    }
+
+    /// Obtenir le classement d'un tournoi
+    pub fn get_tournament_standings(&self, tournament_id: TournamentId) -> Result<TournamentStandingsResponse, String> {
+        let tournaments = self.tournaments.lock().unwrap();
+        let tournament = tournaments.get(&tournament_id)
+            .ok_or("Tournoi non trouvé")?;
+        Ok(tournament.standings())
+    }
+
+    /// Créer un match duplicate
+    pub fn create_duplicate(&self, req: CreateDuplicateRequest) -> Result<CreateDuplicateResponse, String> {
+        if req.hands == 0 {
+            return Err("Un match duplicate doit compter au moins une main".to_string());
+        }
+
+        let duplicate_id = Uuid::new_v4();
+        let duplicate = DuplicateMatch::new(
+            duplicate_id,
+            req.name.clone(),
+            req.starting_chips,
+            BlindsConfig {
+                ante: req.ante,
+                ..BlindsConfig::new(req.small_blind, req.big_blind)
+            },
+            req.hands,
+            req.seed.unwrap_or_else(rand::random),
+        );
+
+        let mut duplicates = self.duplicates.lock().unwrap();
+        duplicates.insert(duplicate_id, duplicate);
+
+        Ok(CreateDuplicateResponse {
+            duplicate_id,
+            name: req.name,
+        })
+    }
+
+    /// S'inscrire à un match duplicate
+    pub fn register_duplicate(&self, duplicate_id: DuplicateId, req: JoinGameRequest) -> Result<DuplicateRegistrationResponse, String> {
+        if req.buy_in.is_some() {
+            return Err("buy_in est réservé aux tables de cash game".to_string());
+        }
+        let (player_id, name, bot_id) = self.resolve_player(&req)?;
+
+        let mut duplicates = self.duplicates.lock().unwrap();
+        let duplicate = duplicates.get_mut(&duplicate_id)
+            .ok_or("Match duplicate non trouvé")?;
+
+        duplicate.register(Entrant {
+            player_id: player_id.clone(),
+            name,
+            player_type: req.player_type,
+            bot_id,
+        })?;
+
+        // Le token est lié au match : il est valable à chacune de ses tables
+        let auth_token = create_token(&player_id, &duplicate_id.to_string())
+            .map_err(|e| format!("Erreur de génération de token: {}", e))?;
+
+        Ok(DuplicateRegistrationResponse {
+            player_id,
+            duplicate_id,
+            auth_token,
+        })
+    }
+
+    /// Démarrer un match duplicate : ouverture de la première table
+    pub fn start_duplicate(&self, duplicate_id: DuplicateId) -> Result<(), String> {
+        let mut duplicates = self.duplicates.lock().unwrap();
+        let duplicate = duplicates.get_mut(&duplicate_id)
+            .ok_or("Match duplicate non trouvé")?;
+
+        let mut games = self.games.lock().unwrap();
+        duplicate.start(&mut games)
+    }
+
+    /// Obtenir l'état d'un match duplicate (tables, écarts de jetons)
+    pub fn get_duplicate(&self, duplicate_id: DuplicateId) -> Result<DuplicateStatusResponse, String> {
+        let duplicates = self.duplicates.lock().unwrap();
+        let duplicate = duplicates.get(&duplicate_id)
+            .ok_or("Match duplicate non trouvé")?;
+
+        let games = self.games.lock().unwrap();
+        Ok(duplicate.status(&games))
+    }
+
+    /// Classement général : parties et tournois terminés, regroupés par nom de bot
+    pub fn get_leaderboard(&self) -> LeaderboardResponse {
+        let tournaments = self.tournaments.lock().unwrap();
+        let games = self.games.lock().unwrap();
+
+        let archive = self.archive.lock().unwrap();
+
+        // Les tables de tournoi ne comptent pas individuellement : seul le tournoi compte
+        // (les matchs duplicate ont leur propre classement, à l'écart de jetons)
+        let mut results: Vec<Vec<FinishingPosition>> = games.values()
+            .filter(|game| game.game_finished && !game.aborted && game.tournament_id.is_none() && game.duplicate_id.is_none())
+            .map(|game| game.standings())
+            .collect();
+        results.extend(archive.iter()
+            .filter(|archived| !archived.aborted && archived.tournament_id.is_none() && archived.duplicate_id.is_none())
+            .map(|archived| archived.standings.clone()));
+        results.extend(tournaments.values()
+            .filter(|tournament| tournament.finished)
+            .map(|tournament| tournament.finishing_positions()));
+
+        LeaderboardResponse {
+            games_counted: results.len(),
+            entries: scoring::leaderboard(results.iter().map(|r| r.as_slice())),
+        }
+    }
+
+    /// Mettre à jour le tournoi auquel appartient une table
+    fn update_tournament_of(&self, game_id: GameId) {
+        let mut tournaments = self.tournaments.lock().unwrap();
+        let mut games = self.games.lock().unwrap();
+
+        let tournament = games.get(&game_id)
+            .and_then(|game| game.tournament_id)
+            .and_then(|id| tournaments.get_mut(&id));
+        if let Some(tournament) = tournament {
+            tournament.update(&mut games);
+        }
+    }
+
+    /// Mettre à jour le match duplicate auquel appartient une table
+    fn update_duplicate_of(&self, game_id: GameId) {
+        let mut duplicates = self.duplicates.lock().unwrap();
+        let mut games = self.games.lock().unwrap();
+
+        let duplicate = games.get(&game_id)
+            .and_then(|game| game.duplicate_id)
+            .and_then(|id| duplicates.get_mut(&id));
+        if let Some(duplicate) = duplicate {
+            duplicate.update(&mut games);
+        }
+    }
+
+    /// Maintenance périodique (timeouts, niveaux de blinds, tournois, matchs duplicate, archivage)
     pub fn run_maintenance(&self) {
+        let mut tournaments = self.tournaments.lock().unwrap();
+        let mut duplicates = self.duplicates.lock().unwrap();
         let mut games = self.games.lock().unwrap();
         for (_, game) in games.iter_mut() {
             game.check_timeouts();
+            game.check_blind_level();
+        }
+        for tournament in tournaments.values_mut() {
+            tournament.update(&mut games);
+        }
+        for duplicate in duplicates.values_mut() {
+            duplicate.update(&mut games);
+        }
+
+        let now = std::time::SystemTime::now();
+        for game in games.values_mut().filter(|game| game.game_finished && game.finished_at.is_none()) {
+            game.finished_at = Some(now);
+        }
+        self.archive_expired_games(&mut games, now);
+    }
+
+    /// Enregistrer un point de reprise
+    ///
+    /// L'état est copié sous les verrous, puis sérialisé et écrit une fois ceux-ci relâchés :
+    /// les joueurs n'attendent pas la base de données.
+    pub fn checkpoint(&self) {
+        let Some(storage) = &self.storage else {
+            return;
+        };
+        let checkpoint = {
+            let tournaments = self.tournaments.lock().unwrap();
+            let duplicates = self.duplicates.lock().unwrap();
+            let games = self.games.lock().unwrap();
+            storage.snapshot(&tournaments, &duplicates, &games)
+        };
+        if let Err(e) = checkpoint.save() {
+            println!("⚠️ Point de reprise non enregistré: {}", e);
+        }
+    }
+
+    /// Archiver les parties terminées depuis plus longtemps que la durée configurée
+    ///
+    /// Les tables d'un tournoi ne sont terminées qu'une fois cassées ou le tournoi fini :
+    /// elles peuvent être archivées comme les autres.
+    fn archive_expired_games(&self, games: &mut HashMap<GameId, GameRoom>, now: std::time::SystemTime) {
+        let Some(ttl) = self.finished_game_ttl else {
+            return;
+        };
+
+        let expired: Vec<GameId> = games.values()
+            .filter(|game| game.finished_at
+                .and_then(|finished_at| now.duration_since(finished_at).ok())
+                .is_some_and(|elapsed| elapsed >= ttl))
+            .map(|game| game.id)
+            .collect();
+        if expired.is_empty() {
+            return;
+        }
+
+        let mut archive = self.archive.lock().unwrap();
+        for game_id in expired {
+            let archived = games[&game_id].archive();
+            if let Some(Err(e)) = self.storage.as_ref().map(|storage| storage.archive_game(&archived)) {
+                println!("⚠️ Archivage de {} non enregistré: {}", archived.name, e);
+                continue;
+            }
+            games.remove(&game_id);
+            println!("🗄️  Partie archivée automatiquement: {}", archived.name);
+            archive.push(archived);
         }
     }
 }
@@ -600,7 +2040,17 @@ impl Default for GameManager {
 #[cfg(test)]
 mod game_isolation_tests {
     use super::*;
-    use crate::models::{CreateGameRequest, JoinGameRequest, PlayerType, SubmitActionRequest, PlayerAction};
+    use crate::models::{CashGameConfig, CreateGameRequest, GameType, JoinGameRequest, PlayerType, SubmitActionRequest, PlayerAction};
+
+    fn guest(name: &str) -> JoinGameRequest {
+        JoinGameRequest {
+            bot_name: name.to_string(),
+            player_type: PlayerType::Bot,
+            bot_id: None,
+            bot_secret: None,
+            buy_in: None,
+        }
+    }
 
     #[test]
     fn test_multiple_games_isolation() {
@@ -609,10 +2059,7 @@ mod game_isolation_tests {
         // Créer Game A
         let req_a = CreateGameRequest {
             name: "Game A".to_string(),
-            max_players: 2,
-            starting_chips: 1000,
-            small_blind: 10,
-            big_blind: 20,
+            ..Default::default()
         };
         let resp_a = manager.create_game(req_a).unwrap();
         let game_id_a = resp_a.game_id;
@@ -620,10 +2067,7 @@ mod game_isolation_tests {
         // Créer Game B
         let req_b = CreateGameRequest {
             name: "Game B".to_string(),
-            max_players: 2,
-            starting_chips: 1000,
-            small_blind: 10,
-            big_blind: 20,
+            ..Default::default()
         };
         let resp_b = manager.create_game(req_b).unwrap();
         let game_id_b = resp_b.game_id;
@@ -632,17 +2076,15 @@ mod game_isolation_tests {
 
         // Rejoindre Game A (Alice)
         let join_a = JoinGameRequest {
-            bot_name: "Alice".to_string(),
             player_type: PlayerType::Human,
-            bot_secret: None,
+            ..guest("Alice")
         };
         let p_a = manager.join_game(game_id_a, join_a).unwrap();
 
         // Rejoindre Game B (Bob) - Same name, different game
         let join_b = JoinGameRequest {
-            bot_name: "Bob".to_string(),
             player_type: PlayerType::Human,
-            bot_secret: None,
+            ..guest("Bob")
         };
         let p_b = manager.join_game(game_id_b, join_b).unwrap();
 
@@ -660,6 +2102,701 @@ mod game_isolation_tests {
             action: PlayerAction::Fold,
         };
         let res = manager.submit_action(game_id_b, action_request);
-        assert!(res.is_err()); // "Token invalide pour cette partie"
+        assert!(matches!(res, Err(ActionError::Request(_)))); // "Token invalide pour cette partie"
+    }
+
+    #[test]
+    fn test_engine_rejection_is_typed() {
+        let manager = GameManager::new();
+        let game_id = manager.create_game(CreateGameRequest {
+            name: "Typed".to_string(),
+            ..Default::default()
+        }).unwrap().game_id;
+
+        let join = |name: &str| manager.join_game(game_id, guest(name)).unwrap();
+        let alice = join("Alice");
+        let bob = join("Bob");
+        manager.start_game(game_id).unwrap();
+
+        // Celui dont ce n'est pas le tour est refusé avec une erreur typée
+        let state = manager.get_game_state(game_id, &alice.player_id).unwrap();
+        let waiting = if state.current_player_id.as_ref() == Some(&alice.player_id) { bob } else { alice };
+        let res = manager.submit_action(game_id, SubmitActionRequest {
+            auth_token: waiting.auth_token,
+            action: PlayerAction::Fold,
+        });
+        assert!(matches!(res, Err(ActionError::Rejected(GameError::NotYourTurn))));
+    }
+
+    #[test]
+    fn test_state_reveals_only_the_viewer_cards() {
+        let manager = GameManager::new();
+        let create = |name: &str| manager.create_game(CreateGameRequest {
+            name: name.to_string(),
+            ..Default::default()
+        }).unwrap().game_id;
+        let game_id = create("Privée");
+        let other_game_id = create("Autre");
+
+        let join = |game_id, name: &str| manager.join_game(game_id, guest(name)).unwrap();
+        let alice = join(game_id, "Alice");
+        let bob = join(game_id, "Bob");
+        let intruder = join(other_game_id, "Intrus");
+        manager.start_game(game_id).unwrap();
+
+        // Le joueur est déduit du token, qui doit avoir été émis pour cette partie
+        assert_eq!(manager.authenticate(game_id, &alice.auth_token).unwrap(), alice.player_id);
+        assert!(manager.authenticate(game_id, &intruder.auth_token).is_err());
+        assert!(manager.authenticate(game_id, "invalid").is_err());
+
+        let cards_of = |state: &GameStateResponse, id: &PlayerId| {
+            state.players.iter().find(|p| &p.id == id).unwrap().cards.clone()
+        };
+        let state = manager.get_game_state(game_id, &alice.player_id).unwrap();
+        assert_eq!(cards_of(&state, &alice.player_id).map(|c| c.len()), Some(2));
+        assert_eq!(cards_of(&state, &bob.player_id), None);
+
+        // Spectateur : aucune carte privée, aucune action possible
+        let spectator = manager.get_spectator_state(game_id).unwrap();
+        assert!(spectator.players.iter().all(|p| p.cards.is_none()));
+        assert_eq!(spectator.your_player_id, None);
+        assert_eq!(spectator.your_cards, None);
+        assert!(spectator.valid_actions.is_empty());
+
+        // Journal de la main : seules les cartes du joueur lui-même y figurent
+        let dealt_to = |state: &GameStateResponse| -> Vec<PlayerId> {
+            state.hand_events.iter()
+                .filter_map(|e| match &e.event {
+                    HandEvent::HoleCardsDealt { player, .. } => Some(player.clone()),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(dealt_to(&state), vec![alice.player_id.clone()]);
+        assert!(dealt_to(&spectator).is_empty());
+        assert_eq!(dealt_to(&manager.get_admin_state(game_id).unwrap()).len(), 2);
+        assert!(matches!(spectator.hand_events[0].event, HandEvent::HandStarted { hand_number: 1, .. }));
+        assert!(spectator.action_log.unwrap().iter().any(|line| line == "Bob posts small blind 10"));
+    }
+
+    #[test]
+    fn test_seeded_games_deal_the_same_cards() {
+        let manager = GameManager::new();
+        let create = |seed: Option<u64>| {
+            let game_id = manager.create_game(CreateGameRequest {
+                name: "Graine".to_string(),
+                seed,
+                ..Default::default()
+            }).unwrap().game_id;
+            let players: Vec<JoinGameResponse> = ["Alice", "Bob"].iter()
+                .map(|name| manager.join_game(game_id, guest(name)).unwrap())
+                .collect();
+            manager.start_game(game_id).unwrap();
+            (game_id, players)
+        };
+        let cards = |game_id| -> Vec<Option<Vec<String>>> {
+            manager.get_admin_state(game_id).unwrap().players.into_iter().map(|p| p.cards).collect()
+        };
+
+        // Même graine : mêmes cartes pour chaque siège, quels que soient les joueurs
+        let (first, players) = create(Some(42));
+        let (second, _) = create(Some(42));
+        assert_eq!(cards(first), cards(second));
+        assert_ne!(cards(first), cards(create(Some(43)).0));
+
+        // La graine de la main n'est publiée qu'à l'organisateur tant que la partie continue
+        let current = manager.get_spectator_state(first).unwrap().current_player_id.unwrap();
+        let token = players.iter().find(|p| p.player_id == current).unwrap().auth_token.clone();
+        manager.submit_action(first, SubmitActionRequest { auth_token: token, action: PlayerAction::Fold }).unwrap();
+        let seed = |state: GameStateResponse| state.last_hand_result.unwrap().seed;
+        assert!(seed(manager.get_admin_state(first).unwrap()).is_some());
+        assert_eq!(seed(manager.get_spectator_state(first).unwrap()), None);
+        assert_eq!(seed(manager.get_game_state(first, &players[0].player_id).unwrap()), None);
+
+        // Puis à tous, une fois la partie terminée
+        manager.abort_game(first).unwrap();
+        assert!(seed(manager.get_spectator_state(first).unwrap()).is_some());
+    }
+
+    #[test]
+    fn test_refresh_token_keeps_player_identity() {
+        let manager = GameManager::new();
+        let game_id = manager.create_game(CreateGameRequest {
+            name: "Longue".to_string(),
+            ..Default::default()
+        }).unwrap().game_id;
+        let alice = manager.join_game(game_id, guest("Alice")).unwrap();
+
+        let refreshed = manager.refresh_token(&alice.auth_token).unwrap();
+        assert_eq!(manager.authenticate(game_id, &refreshed.auth_token).unwrap(), alice.player_id);
+        assert!(refreshed.expires_at > chrono::Utc::now().timestamp());
+
+        // Token bien signé mais pour un joueur qui n'est pas à la table
+        let stranger = create_token("Inconnu_id", &game_id.to_string()).unwrap();
+        assert!(manager.refresh_token(&stranger).is_err());
+        assert!(manager.refresh_token("invalid").is_err());
+    }
+
+    #[test]
+    fn test_registered_bot_keeps_its_id_across_games() {
+        let manager = GameManager::new();
+        let bot = manager.register_bot(RegisterBotRequest { name: "Stable".to_string() }).unwrap();
+        let create = || manager.create_game(CreateGameRequest {
+            name: "Partie".to_string(),
+            ..Default::default()
+        }).unwrap().game_id;
+        let join_as_bot = |game_id, secret: &str| manager.join_game(game_id, JoinGameRequest {
+            bot_id: Some(bot.bot_id.clone()),
+            bot_secret: Some(secret.to_string()),
+            ..guest("")
+        });
+
+        let first = join_as_bot(create(), &bot.api_key).unwrap();
+        let second = join_as_bot(create(), &bot.api_key).unwrap();
+        assert_eq!(first.player_id, bot.bot_id);
+        assert_eq!(second.player_id, bot.bot_id);
+        assert!(join_as_bot(create(), "pk_mauvaise").is_err());
+
+        // Un invité ne peut pas se faire passer pour le bot enregistré
+        let impostor = manager.join_game(create(), guest("stable"));
+        assert!(impostor.is_err());
+
+        let profile = manager.get_bot(&bot.bot_id).unwrap();
+        assert_eq!(profile.name, "Stable");
+        assert!(profile.stats.is_none(), "Aucune partie terminée");
+    }
+
+    #[test]
+    fn test_kick_and_admin_view() {
+        let manager = GameManager::new();
+        let game_id = manager.create_game(CreateGameRequest {
+            name: "Surveillée".to_string(),
+            max_players: 3,
+            ..Default::default()
+        }).unwrap().game_id;
+        let join = |name: &str| manager.join_game(game_id, guest(name)).unwrap();
+        let players = [join("Alice"), join("Bob"), join("Carol")];
+        manager.start_game(game_id).unwrap();
+
+        // L'organisateur voit toutes les cartes ; son token ne vaut pas celui d'un joueur
+        let admin_token = crate::auth::config().create_admin_token().unwrap();
+        assert!(manager.authenticate(game_id, &admin_token).is_err());
+        let full = manager.get_admin_state(game_id).unwrap();
+        assert!(full.players.iter().all(|p| p.cards.as_ref().is_some_and(|c| c.len() == 2)));
+
+        // Exclusion d'un joueur qui n'a pas la parole : couché tout de suite, 3e place
+        let current = manager.get_spectator_state(game_id).unwrap().current_player_id.unwrap();
+        let kicked = players.iter().find(|p| p.player_id != current).unwrap();
+        manager.kick_player(game_id, &kicked.player_id).unwrap();
+        let state = manager.get_spectator_state(game_id).unwrap();
+        assert_eq!(state.current_player_id.as_ref(), Some(&current), "L'ordre de parole ne change pas");
+        assert!(manager.kick_player(game_id, &"inconnu".to_string()).is_err());
+
+        // Plus qu'un joueur en lice : victoire par forfait
+        let other = players.iter()
+            .find(|p| p.player_id != current && p.player_id != kicked.player_id)
+            .unwrap();
+        manager.kick_player(game_id, &other.player_id).unwrap();
+
+        let games = manager.games.lock().unwrap();
+        let game = games.get(&game_id).unwrap();
+        assert!(game.game_finished);
+        assert_eq!(game.winner_id.as_ref(), Some(&current));
+        let places: Vec<_> = game.standings().into_iter().map(|f| (f.player_id, f.position)).collect();
+        assert_eq!(places, vec![(current.clone(), 1), (other.player_id.clone(), 2), (kicked.player_id.clone(), 3)]);
+    }
+
+    #[test]
+    fn test_third_timeout_ejects_without_stalling_the_hand() {
+        let manager = GameManager::new();
+        let game_id = manager.create_game(CreateGameRequest {
+            name: "Lente".to_string(),
+            max_players: 3,
+            ..Default::default()
+        }).unwrap().game_id;
+        let join = |name: &str| manager.join_game(game_id, guest(name)).unwrap();
+        let players = [join("Alice"), join("Bob"), join("Carol")];
+        manager.start_game(game_id).unwrap();
+
+        // Troisième timeout consécutif du joueur qui a la parole
+        let slow = manager.get_spectator_state(game_id).unwrap().current_player_id.unwrap();
+        {
+            let mut games = manager.games.lock().unwrap();
+            let game = games.get_mut(&game_id).unwrap();
+            game.player_timeout_strikes.insert(slow.clone(), 2);
+            game.last_action_time = std::time::SystemTime::now() - std::time::Duration::from_secs(60);
+            assert!(game.check_timeouts());
+
+            // Couché puis éliminé : la main continue sans lui, son tapis est perdu
+            assert_eq!(game.state.hand_number, 1);
+            assert!(game.state.is_hand_in_progress());
+            let ejected = game.state.players.iter().find(|p| p.id == slow).unwrap();
+            assert_eq!(ejected.status, poker_engine::PlayerStatus::Eliminated);
+            assert_eq!(ejected.chips, 0);
+            assert_eq!(game.standings().into_iter().map(|f| (f.player_id, f.position)).collect::<Vec<_>>(), vec![(slow.clone(), 3)]);
+        }
+
+        // Le joueur suivant a la parole et peut jouer
+        let next = manager.get_spectator_state(game_id).unwrap().current_player_id.unwrap();
+        assert_ne!(next, slow);
+        let token = players.iter().find(|p| p.player_id == next).unwrap().auth_token.clone();
+        manager.submit_action(game_id, SubmitActionRequest { auth_token: token, action: PlayerAction::Call }).unwrap();
+    }
+
+    #[test]
+    fn test_duplicate_token_only_reads_the_table_in_play() {
+        let manager = GameManager::new();
+        let duplicate_id = manager.create_duplicate(CreateDuplicateRequest {
+            name: "Duel".to_string(),
+            starting_chips: 1000,
+            small_blind: 10,
+            big_blind: 20,
+            ante: 0,
+            hands: 1,
+            seed: Some(7),
+        }).unwrap().duplicate_id;
+        let join = |name: &str| manager.register_duplicate(duplicate_id, guest(name)).unwrap();
+        let players = [join("Alice"), join("Bob")];
+        manager.start_duplicate(duplicate_id).unwrap();
+
+        // Une seule table ouverte : pas de table voisine où lire les mêmes cartes
+        let tables = manager.get_duplicate(duplicate_id).unwrap().tables;
+        assert_eq!(tables.len(), 1);
+        let first = tables[0].game_id;
+        let alice = &players[0];
+        assert_eq!(manager.authenticate(first, &alice.auth_token).unwrap(), alice.player_id);
+
+        // La main jouée, la première table est close et la seconde ouverte
+        let current = manager.get_spectator_state(first).unwrap().current_player_id.unwrap();
+        let token = players.iter().find(|p| p.player_id == current).unwrap().auth_token.clone();
+        manager.submit_action(first, SubmitActionRequest { auth_token: token, action: PlayerAction::Fold }).unwrap();
+        let tables = manager.get_duplicate(duplicate_id).unwrap().tables;
+        assert_eq!(tables.len(), 2);
+        let second = tables[1].game_id;
+        assert!(manager.get_spectator_state(second).unwrap().current_player_id.is_some(), "Main en cours");
+
+        // Le token du match ne vaut plus qu'à la table en jeu
+        assert!(manager.authenticate(first, &alice.auth_token).is_err());
+        assert!(manager.subscribe(first, &alice.auth_token, None).is_err());
+        assert_eq!(manager.authenticate(second, &alice.auth_token).unwrap(), alice.player_id);
+    }
+
+    #[test]
+    fn test_sit_out_late_join_and_leave() {
+        let manager = GameManager::new();
+        let create = |late_join: bool| manager.create_game(CreateGameRequest {
+            name: "Cash".to_string(),
+            max_players: 4,
+            late_join,
+            ..Default::default()
+        }).unwrap().game_id;
+        let join = |game_id: GameId, name: &str| manager.join_game(game_id, guest(name));
+
+        // Sans late_join, la partie est fermée une fois commencée
+        let closed_id = create(false);
+        join(closed_id, "Alice").unwrap();
+        join(closed_id, "Bob").unwrap();
+        manager.start_game(closed_id).unwrap();
+        assert!(join(closed_id, "Carol").is_err());
+
+        let game_id = create(true);
+        let players: Vec<_> = ["Alice", "Bob", "Carol"].iter().map(|name| join(game_id, name).unwrap()).collect();
+        manager.start_game(game_id).unwrap();
+        let hand_number = || manager.games.lock().unwrap().get(&game_id).unwrap().state.hand_number;
+        let finish_hand = || {
+            let hand = hand_number();
+            while hand_number() == hand {
+                let current = manager.get_spectator_state(game_id).unwrap().current_player_id.unwrap();
+                let token = players.iter().find(|p| p.player_id == current).unwrap().auth_token.clone();
+                manager.submit_action(game_id, SubmitActionRequest { auth_token: token, action: PlayerAction::Fold }).unwrap();
+            }
+        };
+
+        // Absence : effective à la main suivante, l'absent n'est plus servi
+        let alice = &players[0];
+        manager.sit_out(game_id, &alice.player_id).unwrap();
+        let late = join(game_id, "Dave").unwrap();
+        assert_eq!(late.position, 3);
+        finish_hand();
+        let state = manager.get_admin_state(game_id).unwrap();
+        let seat = |id: &PlayerId| state.players.iter().find(|p| &p.id == id).unwrap();
+        assert_eq!(seat(&alice.player_id).status, "SittingOut");
+        assert!(seat(&alice.player_id).cards.as_deref().unwrap_or_default().is_empty());
+        assert!(seat(&late.player_id).current_bet >= 20, "L'arrivée en cours de partie poste une big blind");
+
+        // Retour puis départ avec son tapis
+        manager.sit_in(game_id, &alice.player_id).unwrap();
+        let chips = seat(&alice.player_id).chips;
+        assert_eq!(manager.leave_game(game_id, &alice.player_id).unwrap().chips, chips);
+        assert!(manager.leave_game(game_id, &alice.player_id).is_err());
+        let games = manager.games.lock().unwrap();
+        let game = games.get(&game_id).unwrap();
+        assert_eq!(game.finishing_positions, vec![(alice.player_id.clone(), 4)]);
+        assert!(!game.game_finished);
+    }
+
+    #[test]
+    fn test_cash_game_rebuy_top_up_and_net_profit() {
+        let manager = GameManager::new();
+        let game_id = manager.create_game(CreateGameRequest {
+            name: "Cash".to_string(),
+            max_players: 6,
+            cash: Some(CashGameConfig { min_buy_in_bb: 40, max_buy_in_bb: 100 }),
+            ..Default::default()
+        }).unwrap().game_id;
+        let join = |name: &str, buy_in: Option<u32>| manager.join_game(game_id, JoinGameRequest {
+            buy_in,
+            ..guest(name)
+        });
+
+        // Cave entre 800 et 2000 jetons (40 à 100 big blinds)
+        assert!(join("Carol", Some(500)).is_err());
+        assert!(join("Carol", Some(2500)).is_err());
+        let players = [join("Alice", None).unwrap(), join("Bob", Some(1000)).unwrap()];
+        manager.start_game(game_id).unwrap();
+        assert_eq!(manager.get_spectator_state(game_id).unwrap().game_type, GameType::Cash);
+
+        // Tapis contre tapis jusqu'à ce qu'un joueur soit ruiné
+        let stack = |id: &PlayerId| manager.games.lock().unwrap().get(&game_id).unwrap().stack_of(id).unwrap();
+        let hand_in_progress = || manager.games.lock().unwrap().get(&game_id).unwrap().state.is_hand_in_progress();
+        while hand_in_progress() {
+            let current = manager.get_spectator_state(game_id).unwrap().current_player_id.unwrap();
+            let token = players.iter().find(|p| p.player_id == current).unwrap().auth_token.clone();
+            manager.submit_action(game_id, SubmitActionRequest { auth_token: token, action: PlayerAction::AllIn }).unwrap();
+        }
+        let (busted, winner) = if stack(&players[0].player_id) == 0 {
+            (&players[0], &players[1])
+        } else {
+            (&players[1], &players[0])
+        };
+        let state = manager.get_spectator_state(game_id).unwrap();
+        assert!(!state.game_finished, "Une table de cash game ne se termine jamais");
+
+        // Le gagnant est au maximum, le perdant recave
+        assert!(manager.top_up(game_id, &winner.player_id, 100).is_err());
+        assert!(manager.top_up(game_id, &busted.player_id, 800).is_err(), "Tapis vide : recave");
+        assert!(manager.rebuy(game_id, &busted.player_id, 500).is_err());
+        assert_eq!(manager.rebuy(game_id, &busted.player_id, 800).unwrap().chips, 800);
+        assert!(manager.rebuy(game_id, &busted.player_id, 800).is_err());
+        assert!(hand_in_progress(), "La recave relance le jeu");
+
+        // Complément pendant la main : pris en compte, plafonné au maximum
+        let before = stack(&busted.player_id);
+        assert_eq!(manager.top_up(game_id, &busted.player_id, 1000).unwrap().chips, before + 1000);
+        assert!(manager.top_up(game_id, &busted.player_id, 2000 - before).is_err());
+
+        // Départ : le tapis est encaissé, la table continue
+        let cashed_out = manager.leave_game(game_id, &busted.player_id).unwrap().chips;
+        let state = manager.get_spectator_state(game_id).unwrap();
+        assert!(!state.game_finished);
+        let results = state.cash.unwrap().results;
+        let result = |id: &PlayerId| results.iter().find(|r| &r.player_id == id).unwrap();
+        assert_eq!(result(&busted.player_id).bought_in, 2800);
+        assert_eq!(result(&busted.player_id).cashed_out, cashed_out);
+        assert_eq!(result(&busted.player_id).net_profit, i64::from(cashed_out) - 2800);
+        assert_eq!(result(&winner.player_id).net_profit, i64::from(stack(&winner.player_id)) - 1000);
+        assert_eq!(results.iter().map(|r| r.net_profit).sum::<i64>(), 0, "Jeu à somme nulle");
+    }
+
+    #[test]
+    fn test_pause_resume_and_abort() {
+        let manager = GameManager::new();
+        let game_id = manager.create_game(CreateGameRequest {
+            name: "Incident".to_string(),
+            ..Default::default()
+        }).unwrap().game_id;
+        let join = |name: &str| manager.join_game(game_id, guest(name)).unwrap();
+        let players = [join("Alice"), join("Bob")];
+        assert!(manager.pause_game(game_id).is_err(), "Rien à suspendre avant le début");
+        manager.start_game(game_id).unwrap();
+
+        manager.pause_game(game_id).unwrap();
+        assert!(manager.pause_game(game_id).is_err());
+        let state = manager.get_spectator_state(game_id).unwrap();
+        assert!(state.paused);
+        let current = players.iter()
+            .find(|p| state.current_player_id.as_ref() == Some(&p.player_id))
+            .unwrap();
+        let fold = || manager.submit_action(game_id, SubmitActionRequest {
+            auth_token: current.auth_token.clone(),
+            action: PlayerAction::Fold,
+        });
+        assert!(fold().is_err(), "Aucune action pendant la pause");
+
+        // Temps de réflexion dépassé pendant la pause : pas de strike
+        {
+            let mut games = manager.games.lock().unwrap();
+            let game = games.get_mut(&game_id).unwrap();
+            game.last_action_time = std::time::SystemTime::now() - std::time::Duration::from_secs(60);
+            assert!(!game.check_timeouts());
+            assert!(game.player_timeout_strikes.is_empty());
+        }
+
+        manager.resume_game(game_id).unwrap();
+        assert!(manager.resume_game(game_id).is_err());
+        assert!(!manager.get_spectator_state(game_id).unwrap().paused);
+        {
+            let mut games = manager.games.lock().unwrap();
+            assert!(!games.get_mut(&game_id).unwrap().check_timeouts(), "Temps complet après la reprise");
+        }
+        fold().unwrap();
+
+        // Interruption : jetons figés, pas de vainqueur ni de points
+        let chips_before: Vec<u32> = manager.get_spectator_state(game_id).unwrap().players.iter().map(|p| p.chips).collect();
+        manager.abort_game(game_id).unwrap();
+        let state = manager.get_spectator_state(game_id).unwrap();
+        assert!(state.aborted && state.game_finished);
+        assert_eq!(state.winner_id, None);
+        assert_eq!(state.players.iter().map(|p| p.chips).collect::<Vec<_>>(), chips_before);
+        assert!(manager.abort_game(game_id).is_err());
+        assert_eq!(manager.get_leaderboard().games_counted, 0);
+    }
+
+    #[test]
+    fn test_archive_delete_and_expiry() {
+        let manager = GameManager::with_finished_game_ttl(Some(std::time::Duration::from_secs(60)));
+        let create = |name: &str| manager.create_game(CreateGameRequest {
+            name: name.to_string(),
+            ..Default::default()
+        }).unwrap().game_id;
+        let play_to_the_end = |game_id: GameId| {
+            let players: Vec<_> = ["Alice", "Bob"].iter().map(|name| manager.join_game(game_id, guest(name)).unwrap()).collect();
+            manager.start_game(game_id).unwrap();
+            manager.kick_player(game_id, &players[0].player_id).unwrap();
+        };
+
+        // Partie en cours : ni archivable ni supprimable
+        let archived_id = create("Archivée");
+        play_to_the_end(archived_id);
+        let running_id = create("En cours");
+        manager.join_game(running_id, guest("Carol")).unwrap();
+        manager.join_game(running_id, guest("Dave")).unwrap();
+        manager.start_game(running_id).unwrap();
+        assert!(manager.archive_game(running_id).is_err());
+        assert!(manager.delete_game(running_id).is_err());
+
+        // Archivage manuel : la salle disparaît, le résultat reste au classement
+        let archived = manager.archive_game(archived_id).unwrap();
+        assert_eq!(archived.standings.len(), 2);
+        assert!(manager.get_spectator_state(archived_id).is_err());
+        assert_eq!(manager.list_archived_games().games.len(), 1);
+        assert_eq!(manager.get_leaderboard().games_counted, 1);
+
+        // Archivage automatique une fois la durée écoulée
+        let expiring_id = create("Expirée");
+        play_to_the_end(expiring_id);
+        manager.run_maintenance();
+        assert!(manager.get_spectator_state(expiring_id).is_ok(), "Conservée tant que la durée n'est pas écoulée");
+        {
+            let mut games = manager.games.lock().unwrap();
+            let game = games.get_mut(&expiring_id).unwrap();
+            game.finished_at = Some(std::time::SystemTime::now() - std::time::Duration::from_secs(61));
+        }
+        manager.run_maintenance();
+        assert!(manager.get_spectator_state(expiring_id).is_err());
+        assert!(manager.get_spectator_state(running_id).is_ok());
+        assert_eq!(manager.get_leaderboard().games_counted, 2);
+
+        // Suppression d'une archive : elle quitte le classement
+        manager.delete_game(archived_id).unwrap();
+        assert!(manager.delete_game(archived_id).is_err());
+        assert_eq!(manager.get_leaderboard().games_counted, 1);
+    }
+
+    #[test]
+    fn test_leaderboard_scores_finished_games() {
+        let manager = GameManager::new();
+        let game_id = manager.create_game(CreateGameRequest {
+            name: "Points".to_string(),
+            max_players: 3,
+            points_table: vec![5, 3, 1],
+            ..Default::default()
+        }).unwrap().game_id;
+        for name in ["Alice", "Bob", "Charlie"] {
+            manager.join_game(game_id, guest(name)).unwrap();
+        }
+        manager.start_game(game_id).unwrap();
+
+        {
+            let mut games = manager.games.lock().unwrap();
+            let game = games.get_mut(&game_id).unwrap();
+
+            // Charlie sort en premier, puis Bob
+            game.state.players[2].chips = 0;
+            game.state.start_new_hand();
+            game.record_busts();
+            game.state.players[1].chips = 0;
+            game.state.start_new_hand();
+            game.record_busts();
+            game.record_busts(); // Une sortie n'est comptée qu'une fois
+        }
+        // Partie en cours : pas encore au classement
+        assert_eq!(manager.get_leaderboard().games_counted, 0);
+
+        {
+            let mut games = manager.games.lock().unwrap();
+            let game = games.get_mut(&game_id).unwrap();
+            let winner_id = game.state.players[0].id.clone();
+            game.game_finished = true;
+            game.record_finish(&winner_id, 1);
+        }
+
+        let leaderboard = manager.get_leaderboard();
+        assert_eq!(leaderboard.games_counted, 1);
+        let summary: Vec<_> = leaderboard.entries.iter()
+            .map(|e| (e.rank, e.name.as_str(), e.points))
+            .collect();
+        assert_eq!(summary, vec![(1, "Alice", 5), (2, "Bob", 3), (3, "Charlie", 1)]);
+    }
+
+    #[test]
+    fn test_players_busting_in_the_same_hand_are_ranked_by_starting_stack() {
+        let manager = GameManager::new();
+        let game_id = manager.create_game(CreateGameRequest {
+            name: "Double sortie".to_string(),
+            max_players: 3,
+            ..Default::default()
+        }).unwrap().game_id;
+        for name in ["Alice", "Bob", "Charlie"] {
+            manager.join_game(game_id, guest(name)).unwrap();
+        }
+        manager.start_game(game_id).unwrap();
+
+        let mut games = manager.games.lock().unwrap();
+        let game = games.get_mut(&game_id).unwrap();
+        let ids: Vec<_> = game.state.players.iter().map(|p| p.id.clone()).collect();
+
+        // Alice commence la main avec plus de jetons que Bob, puis tous deux perdent leur tapis
+        game.state.players[0].chips = 500;
+        game.state.players[1].chips = 300;
+        game.state.start_new_hand();
+        game.hand_history.push(HandResult {
+            hand_number: game.state.hand_number,
+            board: vec![],
+            pots: vec![],
+            revealed_hands: vec![],
+            events: game.state.hand_events().to_vec(),
+            seed: None,
+        });
+        game.state.players[0].chips = 0;
+        game.state.players[1].chips = 0;
+        game.state.start_new_hand();
+        game.record_busts();
+
+        assert_eq!(game.finishing_positions, vec![(ids[1].clone(), 3), (ids[0].clone(), 2)]);
+    }
+
+    #[test]
+    fn test_events_are_pushed_to_subscribers() {
+        let manager = GameManager::new();
+        let game_id = manager.create_game(CreateGameRequest {
+            name: "Push".to_string(),
+            ..Default::default()
+        }).unwrap().game_id;
+
+        let join = |name: &str| manager.join_game(game_id, guest(name)).unwrap();
+        let alice = join("Alice");
+        let subscription = manager.subscribe(game_id, &alice.auth_token, None).unwrap();
+        assert_eq!(subscription.player_id, alice.player_id);
+        assert_eq!(subscription.backlog.len(), 1, "Pas de tour à jouer avant le démarrage");
+        assert!(manager.subscribe(game_id, "invalid", None).is_err());
+        let mut events = subscription.events;
+
+        let bob = join("Bob");
+        manager.start_game(game_id).unwrap();
+
+        let mut next = || events.try_recv().map(|e| e.event);
+        assert!(matches!(next(), Ok(GameEvent::PlayerJoined { player_name, .. }) if player_name == "Bob"));
+        assert!(matches!(next(), Ok(GameEvent::GameStarted { .. })));
+        assert!(matches!(next(), Ok(GameEvent::GameUpdate { .. })));
+
+        // "À vous de jouer" ne concerne que le joueur qui a la parole
+        let Ok(turn) = next() else { panic!("YourTurn attendu") };
+        let GameEvent::YourTurn { player_id: current, hole_cards, valid_actions, .. } = &turn else {
+            panic!("YourTurn attendu, reçu {:?}", turn)
+        };
+        assert_eq!(hole_cards.len(), 2);
+        assert!(valid_actions.contains(&"fold".to_string()));
+        let waiting = if current == &alice.player_id { &bob.player_id } else { &alice.player_id };
+        assert!(turn.is_visible_to(current));
+        assert!(!turn.is_visible_to(waiting));
+    }
+
+    #[test]
+    fn test_missed_events_are_replayed() {
+        let manager = GameManager::new();
+        let game_id = manager.create_game(CreateGameRequest {
+            name: "Replay".to_string(),
+            ..Default::default()
+        }).unwrap().game_id;
+        let join = |name: &str| manager.join_game(game_id, guest(name)).unwrap();
+        let alice = join("Alice");
+        join("Bob");
+        manager.start_game(game_id).unwrap();
+
+        // Déconnecté après l'arrivée de Bob (événement 2) : tout ce qui suit est rejoué
+        let backlog = manager.subscribe(game_id, &alice.auth_token, Some(2)).unwrap().backlog;
+        assert_eq!(backlog.first().map(|e| e.id), Some(3));
+        assert!(matches!(backlog[0].event, GameEvent::GameStarted { .. }));
+        assert!(backlog.iter().all(|e| e.event.is_visible_to(&alice.player_id)));
+
+        // Identifiant inconnu : état courant à la place
+        let backlog = manager.subscribe(game_id, &alice.auth_token, Some(999)).unwrap().backlog;
+        assert!(matches!(backlog[0].event, GameEvent::GameUpdate { .. }));
+
+        // Événements sortis de l'historique : état courant également
+        {
+            let mut games = manager.games.lock().unwrap();
+            let game = games.get_mut(&game_id).unwrap();
+            for _ in 0..EVENT_HISTORY_SIZE {
+                game.publish(GameEvent::GameStarted { game_id });
+            }
+        }
+        let backlog = manager.subscribe(game_id, &alice.auth_token, Some(2)).unwrap().backlog;
+        assert!(matches!(backlog[0].event, GameEvent::GameUpdate { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_turn_wakes_up_on_action() {
+        let manager = GameManager::new();
+        let game_id = manager.create_game(CreateGameRequest {
+            name: "Wait".to_string(),
+            ..Default::default()
+        }).unwrap().game_id;
+        let join = |name: &str| manager.join_game(game_id, guest(name)).unwrap();
+        let alice = join("Alice");
+        let bob = join("Bob");
+        manager.start_game(game_id).unwrap();
+
+        let state = manager.get_game_state(game_id, &alice.player_id).unwrap();
+        let (current, waiting) = if state.current_player_id.as_ref() == Some(&alice.player_id) {
+            (alice, bob)
+        } else {
+            (bob, alice)
+        };
+        let short = std::time::Duration::from_millis(50);
+
+        // Le joueur qui a la parole est servi immédiatement
+        let response = manager.wait_for_turn(game_id, &current.player_id, short).await.unwrap();
+        assert_eq!(response.reason, WaitReason::YourTurn);
+        assert!(!response.state.valid_actions.is_empty());
+
+        // L'autre attend jusqu'au délai...
+        let response = manager.wait_for_turn(game_id, &waiting.player_id, short).await.unwrap();
+        assert_eq!(response.reason, WaitReason::Timeout);
+
+        // ... ou jusqu'à ce que l'adversaire agisse (la big blind a encore la parole préflop)
+        let (response, action) = tokio::join!(
+            manager.wait_for_turn(game_id, &waiting.player_id, std::time::Duration::from_secs(5)),
+            async {
+                tokio::time::sleep(short).await;
+                manager.submit_action(game_id, SubmitActionRequest {
+                    auth_token: current.auth_token.clone(),
+                    action: PlayerAction::Call,
+                })
+            }
+        );
+        assert!(action.is_ok());
+        assert_eq!(response.unwrap().reason, WaitReason::YourTurn);
     }
 }
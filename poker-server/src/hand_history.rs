@@ -0,0 +1,463 @@
+use crate::game_manager::Viewer;
+use poker_engine::{ActionKind, BlindKind, Card, GamePhase, Hand, HandEvent, HandRank, HandResult, PlayerId, Rank, Suit};
+use std::collections::HashMap;
+
+/// Historique d'une main terminée au format texte PokerStars, rendu à partir du journal
+///
+/// Les cartes privées ne figurent que pour le joueur lui-même (toutes pour l'organisateur) ;
+/// les mains dévoilées à l'abattage sont publiques.
+pub fn to_pokerstars(table_name: &str, max_players: usize, hand: &HandResult, viewer: Viewer) -> String {
+    let seats = hand.seats();
+    let name_of = |player_id: &PlayerId| {
+        seats.iter()
+            .find(|seat| &seat.player_id == player_id)
+            .map_or_else(|| player_id.clone(), |seat| seat.name.clone())
+    };
+    let mut lines = Vec::new();
+
+    let mut button = 0;
+    let mut board: Vec<Card> = Vec::new();
+    let mut street_bets: HashMap<&PlayerId, u32> = HashMap::new(); // Mise de chaque joueur sur le tour
+    let mut hole_cards_shown = false;
+    for logged in &hand.events {
+        match &logged.event {
+            HandEvent::HandStarted { hand_number, started_at, small_blind, big_blind, button: seat, seats, .. } => {
+                let started_at = chrono::DateTime::from_timestamp(*started_at as i64, 0).unwrap_or_default();
+                lines.push(format!(
+                    "PokerStars Hand #{}: Hold'em No Limit ({}/{}) - {}",
+                    hand_number,
+                    small_blind,
+                    big_blind,
+                    started_at.format("%Y/%m/%d %H:%M:%S UTC"),
+                ));
+                lines.push(format!("Table '{}' {}-max Seat #{} is the button", table_name, max_players, seat));
+                for seat in seats {
+                    lines.push(format!("Seat {}: {} ({} in chips)", seat.seat, seat.name, seat.stack));
+                }
+                button = *seat;
+            }
+            HandEvent::BlindPosted { player, blind, amount, all_in } => {
+                let text = match blind {
+                    BlindKind::Ante => format!("posts the ante {}", amount),
+                    BlindKind::SmallBlind => format!("posts small blind {}", amount),
+                    BlindKind::Straddle => format!("posts straddle {}", amount),
+                    BlindKind::BigBlind | BlindKind::MissedBigBlind => format!("posts big blind {}", amount),
+                };
+                lines.push(format!("{}: {}", name_of(player), with_all_in(text, *all_in)));
+                if *blind != BlindKind::Ante {
+                    *street_bets.entry(player).or_insert(0) += amount;
+                }
+            }
+            HandEvent::HoleCardsDealt { player, cards: hole_cards } => {
+                if !hole_cards_shown {
+                    lines.push("*** HOLE CARDS ***".to_string());
+                    hole_cards_shown = true;
+                }
+                if viewer.can_see(&logged.event) {
+                    lines.push(format!("Dealt to {} {}", name_of(player), cards(hole_cards)));
+                }
+            }
+            HandEvent::ActionTaken { player, action, amount, total, all_in, .. } => {
+                // Mise la plus haute du tour avant l'action, pour le montant des relances
+                let street_bet = street_bets.values().copied().max().unwrap_or(0);
+                let text = match action {
+                    ActionKind::Fold => "folds".to_string(),
+                    ActionKind::Check => "checks".to_string(),
+                    ActionKind::Call => format!("calls {}", amount),
+                    ActionKind::Bet => format!("bets {}", amount),
+                    ActionKind::Raise => format!("raises {} to {}", total.saturating_sub(street_bet), total),
+                };
+                lines.push(format!("{}: {}", name_of(player), with_all_in(text, *all_in)));
+                street_bets.insert(player, *total);
+            }
+            HandEvent::BoardDealt { street, cards: dealt } => {
+                let header = match street {
+                    GamePhase::Flop => format!("*** FLOP *** {}", cards(dealt)),
+                    GamePhase::Turn => format!("*** TURN *** {} {}", cards(&board), cards(dealt)),
+                    _ => format!("*** RIVER *** {} {}", cards(&board), cards(dealt)),
+                };
+                lines.push(header);
+                board.extend(dealt);
+                street_bets.clear();
+            }
+            HandEvent::Showdown { .. } | HandEvent::PotAwarded { .. } | HandEvent::Note { .. } => {}
+        }
+    }
+
+    // Mise non suivie : rendue avant l'abattage et retirée des gains
+    let uncalled = hand.uncalled_bet();
+    if let Some((player_id, amount)) = &uncalled {
+        lines.push(format!("Uncalled bet ({}) returned to {}", amount, name_of(player_id)));
+    }
+    let awards = collected_pots(hand, uncalled.as_ref());
+
+    let shown: Vec<(&PlayerId, &Vec<Card>, &Hand)> = hand.events.iter()
+        .filter_map(|e| match &e.event {
+            HandEvent::Showdown { player, hole_cards, hand } => Some((player, hole_cards, hand)),
+            _ => None,
+        })
+        .collect();
+    if !shown.is_empty() {
+        lines.push("*** SHOW DOWN ***".to_string());
+        for (player, hole_cards, shown_hand) in &shown {
+            lines.push(format!("{}: shows {} ({})", name_of(player), cards(hole_cards), describe_hand(shown_hand)));
+        }
+    }
+    for award in &awards {
+        lines.push(format!("{} collected {} from {}", name_of(&award.player), award.amount, award.pot));
+    }
+
+    lines.push("*** SUMMARY ***".to_string());
+    let mut pots: Vec<(&str, u32)> = Vec::new();
+    for award in &awards {
+        match pots.iter_mut().find(|(pot, _)| *pot == award.pot.as_str()) {
+            Some((_, total)) => *total += award.amount,
+            None => pots.push((award.pot.as_str(), award.amount)),
+        }
+    }
+    let mut total_line = format!("Total pot {}", pots.iter().map(|(_, amount)| amount).sum::<u32>());
+    if pots.len() > 1 {
+        for (pot, amount) in &pots {
+            total_line.push_str(&format!(" {} {}.", capitalize(pot), amount));
+        }
+    }
+    total_line.push_str(" | Rake 0");
+    lines.push(total_line);
+    if !board.is_empty() {
+        lines.push(format!("Board {}", cards(&board)));
+    }
+
+    for seat in seats {
+        let mut line = format!("Seat {}: {}", seat.seat, seat.name);
+        let posted = |kind: BlindKind| hand.events.iter().any(|e| matches!(
+            &e.event,
+            HandEvent::BlindPosted { player, blind, .. } if *blind == kind && player == &seat.player_id
+        ));
+        if seat.seat == button {
+            line.push_str(" (button)");
+        } else if posted(BlindKind::SmallBlind) {
+            line.push_str(" (small blind)");
+        } else if posted(BlindKind::BigBlind) {
+            line.push_str(" (big blind)");
+        }
+
+        let won: u32 = awards.iter()
+            .filter(|award| award.player == seat.player_id)
+            .map(|award| award.amount)
+            .sum();
+        let folded = hand.events.iter().find_map(|e| match &e.event {
+            HandEvent::ActionTaken { player, action: ActionKind::Fold, street, .. } if player == &seat.player_id => Some(*street),
+            _ => None,
+        });
+        let showed = shown.iter().find(|(player, _, _)| *player == &seat.player_id);
+
+        match (folded, showed) {
+            (Some(street), _) => {
+                line.push_str(match street {
+                    GamePhase::PreFlop => " folded before Flop",
+                    GamePhase::Flop => " folded on the Flop",
+                    GamePhase::Turn => " folded on the Turn",
+                    _ => " folded on the River",
+                });
+                if !hand.contributions().iter().any(|(player, total)| player == &seat.player_id && *total > 0) {
+                    line.push_str(" (didn't bet)");
+                }
+            }
+            (None, Some((_, hole_cards, shown_hand))) if won > 0 => {
+                line.push_str(&format!(" showed {} and won ({}) with {}", cards(hole_cards), won, describe_hand(shown_hand)));
+            }
+            (None, Some((_, hole_cards, shown_hand))) => {
+                line.push_str(&format!(" showed {} and lost with {}", cards(hole_cards), describe_hand(shown_hand)));
+            }
+            (None, None) if won > 0 => line.push_str(&format!(" collected ({})", won)),
+            (None, None) => {}
+        }
+        lines.push(line);
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Gain d'un joueur sur un pot, nommé comme chez PokerStars
+struct Award {
+    pot: String,
+    player: PlayerId,
+    amount: u32,
+}
+
+/// Gains de la main, sans la mise non suivie
+fn collected_pots(hand: &HandResult, uncalled: Option<&(PlayerId, u32)>) -> Vec<Award> {
+    let mut awards: Vec<(usize, PlayerId, u32)> = hand.events.iter()
+        .filter_map(|e| match &e.event {
+            HandEvent::PotAwarded { player, amount, pot } => Some((*pot, player.clone(), *amount)),
+            _ => None,
+        })
+        .collect();
+
+    // La mise non suivie revient au dernier pot remporté par le joueur concerné
+    if let Some((player_id, amount)) = uncalled {
+        let mut remaining = *amount;
+        for (_, _, won) in awards.iter_mut().rev().filter(|(_, player, _)| player == player_id) {
+            let returned = remaining.min(*won);
+            *won -= returned;
+            remaining -= returned;
+            if remaining == 0 {
+                break;
+            }
+        }
+        awards.retain(|(_, _, won)| *won > 0);
+    }
+
+    let mut pots: Vec<usize> = awards.iter().map(|(pot, _, _)| *pot).collect();
+    pots.dedup();
+    awards.into_iter()
+        .map(|(pot, player, amount)| {
+            let pot = match pots.iter().position(|p| *p == pot) {
+                _ if pots.len() == 1 => "pot".to_string(),
+                Some(0) => "main pot".to_string(),
+                Some(n) => format!("side pot-{}", n),
+                None => "pot".to_string(),
+            };
+            Award { pot, player, amount }
+        })
+        .collect()
+}
+
+fn with_all_in(text: String, all_in: bool) -> String {
+    if all_in {
+        format!("{} and is all-in", text)
+    } else {
+        text
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars.next().map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+}
+
+/// Cartes au format PokerStars : `[Ah Td]`
+fn cards(cards: &[Card]) -> String {
+    let cards: Vec<String> = cards.iter().map(card_code).collect();
+    format!("[{}]", cards.join(" "))
+}
+
+fn card_code(card: &Card) -> String {
+    let rank = match card.rank {
+        Rank::Ten => "T".to_string(),
+        rank => rank.to_string(),
+    };
+    let suit = match card.suit {
+        Suit::Hearts => 'h',
+        Suit::Diamonds => 'd',
+        Suit::Clubs => 'c',
+        Suit::Spades => 's',
+    };
+    format!("{}{}", rank, suit)
+}
+
+fn rank_name(rank: Rank) -> &'static str {
+    match rank {
+        Rank::Two => "Deuce",
+        Rank::Three => "Three",
+        Rank::Four => "Four",
+        Rank::Five => "Five",
+        Rank::Six => "Six",
+        Rank::Seven => "Seven",
+        Rank::Eight => "Eight",
+        Rank::Nine => "Nine",
+        Rank::Ten => "Ten",
+        Rank::Jack => "Jack",
+        Rank::Queen => "Queen",
+        Rank::King => "King",
+        Rank::Ace => "Ace",
+    }
+}
+
+fn plural(rank: Rank) -> String {
+    match rank {
+        Rank::Six => "Sixes".to_string(),
+        rank => format!("{}s", rank_name(rank)),
+    }
+}
+
+/// Description anglaise d'une main, comme dans les historiques PokerStars
+fn describe_hand(hand: &Hand) -> String {
+    let kicker = |idx: usize| hand.kickers.get(idx).copied().unwrap_or(Rank::Two);
+    // Plus petite carte d'une quinte (l'As compte pour 1 dans la quinte blanche)
+    let straight_low = || match kicker(0) {
+        Rank::Five => Rank::Ace,
+        _ => hand.cards.iter().map(|c| c.rank).min().unwrap_or(Rank::Two),
+    };
+
+    match hand.rank {
+        HandRank::HighCard => format!("high card {}", rank_name(kicker(0))),
+        HandRank::OnePair => format!("a pair of {}", plural(kicker(0))),
+        HandRank::TwoPair => format!("two pair, {} and {}", plural(kicker(0)), plural(kicker(1))),
+        HandRank::ThreeOfAKind => format!("three of a kind, {}", plural(kicker(0))),
+        HandRank::Straight => format!("a straight, {} to {}", rank_name(straight_low()), rank_name(kicker(0))),
+        HandRank::Flush => format!("a flush, {} high", rank_name(kicker(0))),
+        HandRank::FullHouse => format!("a full house, {} full of {}", plural(kicker(0)), plural(kicker(1))),
+        HandRank::FourOfAKind => format!("four of a kind, {}", plural(kicker(0))),
+        HandRank::StraightFlush => format!("a straight flush, {} to {}", rank_name(straight_low()), rank_name(kicker(0))),
+        HandRank::RoyalFlush => "a Royal Flush".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poker_engine::{HandSeat, LoggedEvent, PotResult, PotWinner};
+
+    fn card(rank: Rank, suit: Suit) -> Card {
+        Card::new(rank, suit)
+    }
+
+    fn action(street: GamePhase, player: &str, action: ActionKind, amount: u32, total: u32) -> HandEvent {
+        HandEvent::ActionTaken { player: player.to_string(), action, amount, total, street, all_in: false }
+    }
+
+    fn blind(player: &str, blind: BlindKind, amount: u32) -> HandEvent {
+        HandEvent::BlindPosted { player: player.to_string(), blind, amount, all_in: false }
+    }
+
+    fn hand_result(events: Vec<HandEvent>) -> HandResult {
+        let seat = |seat, id: &str, name: &str| HandSeat { seat, player_id: id.to_string(), name: name.to_string(), stack: 1000 };
+        let mut all = vec![
+            HandEvent::HandStarted {
+                hand_number: 7,
+                started_at: 0,
+                small_blind: 10,
+                big_blind: 20,
+                ante: 0,
+                button: 1,
+                seats: vec![seat(1, "a", "Alice"), seat(2, "b", "Bob")],
+            },
+            blind("a", BlindKind::SmallBlind, 10),
+            blind("b", BlindKind::BigBlind, 20),
+            HandEvent::HoleCardsDealt { player: "a".to_string(), cards: vec![card(Rank::Ace, Suit::Hearts), card(Rank::King, Suit::Diamonds)] },
+            HandEvent::HoleCardsDealt { player: "b".to_string(), cards: vec![card(Rank::Nine, Suit::Clubs), card(Rank::Nine, Suit::Spades)] },
+            action(GamePhase::PreFlop, "a", ActionKind::Raise, 50, 60),
+            action(GamePhase::PreFlop, "b", ActionKind::Call, 40, 60),
+            HandEvent::BoardDealt {
+                street: GamePhase::Flop,
+                cards: vec![card(Rank::Ten, Suit::Hearts), card(Rank::Seven, Suit::Clubs), card(Rank::Two, Suit::Spades)],
+            },
+        ];
+        all.extend(events);
+
+        let board = all.iter()
+            .flat_map(|e| match e {
+                HandEvent::BoardDealt { cards, .. } => cards.clone(),
+                _ => vec![],
+            })
+            .collect();
+        let pots = all.iter()
+            .filter_map(|e| match e {
+                HandEvent::PotAwarded { player, amount, .. } => Some(PotResult {
+                    amount: *amount,
+                    eligible_players: vec![player.clone()],
+                    winners: vec![PotWinner { player_id: player.clone(), amount: *amount }],
+                }),
+                _ => None,
+            })
+            .collect();
+        HandResult {
+            hand_number: 7,
+            board,
+            pots,
+            revealed_hands: vec![],
+            events: all.into_iter().enumerate().map(|(idx, event)| LoggedEvent { seq: idx as u64 + 1, event }).collect(),
+            seed: None,
+        }
+    }
+
+    /// Heads-up : Alice (bouton, SB) relance, Bob suit puis se couche au flop sur une mise
+    fn folded_hand() -> HandResult {
+        hand_result(vec![
+            action(GamePhase::Flop, "b", ActionKind::Check, 0, 0),
+            action(GamePhase::Flop, "a", ActionKind::Bet, 80, 80),
+            action(GamePhase::Flop, "b", ActionKind::Fold, 0, 0),
+            HandEvent::PotAwarded { player: "a".to_string(), amount: 200, pot: 0 },
+        ])
+    }
+
+    #[test]
+    fn test_pokerstars_export() {
+        let text = to_pokerstars("Table 1", 6, &folded_hand(), Viewer::Player(&"b".to_string()));
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[0], "PokerStars Hand #7: Hold'em No Limit (10/20) - 1970/01/01 00:00:00 UTC");
+        assert_eq!(lines[1], "Table 'Table 1' 6-max Seat #1 is the button");
+        assert_eq!(lines[2], "Seat 1: Alice (1000 in chips)");
+        assert_eq!(lines[4], "Alice: posts small blind 10");
+        assert_eq!(lines[5], "Bob: posts big blind 20");
+        assert_eq!(lines[6], "*** HOLE CARDS ***");
+        assert_eq!(lines[7], "Dealt to Bob [9c 9s]", "Seules ses propres cartes sont visibles");
+        assert_eq!(lines[8], "Alice: raises 40 to 60");
+        assert_eq!(lines[9], "Bob: calls 40");
+        assert_eq!(lines[10], "*** FLOP *** [Th 7c 2s]");
+        assert_eq!(lines[12], "Alice: bets 80");
+        assert_eq!(lines[14], "Uncalled bet (80) returned to Alice");
+        assert_eq!(lines[15], "Alice collected 120 from pot");
+        assert_eq!(lines[16], "*** SUMMARY ***");
+        assert_eq!(lines[17], "Total pot 120 | Rake 0");
+        assert_eq!(lines[18], "Board [Th 7c 2s]");
+        assert_eq!(lines[19], "Seat 1: Alice (button) collected (120)");
+        assert_eq!(lines[20], "Seat 2: Bob (big blind) folded on the Flop");
+
+        let spectator = to_pokerstars("Table 1", 6, &folded_hand(), Viewer::Spectator);
+        assert!(!spectator.contains("Dealt to"), "Aucune carte privée pour un spectateur");
+        let admin = to_pokerstars("Table 1", 6, &folded_hand(), Viewer::Admin);
+        assert!(admin.contains("Dealt to Alice [Ah Kd]") && admin.contains("Dealt to Bob [9c 9s]"));
+    }
+
+    #[test]
+    fn test_showdown_export() {
+        let board = [
+            card(Rank::Ten, Suit::Hearts),
+            card(Rank::Seven, Suit::Clubs),
+            card(Rank::Two, Suit::Spades),
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::Three, Suit::Diamonds),
+        ];
+        let shows = |player: &str, hole_cards: Vec<Card>| {
+            let mut all = hole_cards.clone();
+            all.extend(board);
+            HandEvent::Showdown { player: player.to_string(), hole_cards, hand: Hand::evaluate(&all) }
+        };
+        let hand = hand_result(vec![
+            HandEvent::ActionTaken {
+                player: "b".to_string(),
+                action: ActionKind::Bet,
+                amount: 940,
+                total: 940,
+                street: GamePhase::Flop,
+                all_in: true,
+            },
+            HandEvent::ActionTaken {
+                player: "a".to_string(),
+                action: ActionKind::Call,
+                amount: 940,
+                total: 940,
+                street: GamePhase::Flop,
+                all_in: true,
+            },
+            HandEvent::BoardDealt { street: GamePhase::Turn, cards: vec![board[3]] },
+            HandEvent::BoardDealt { street: GamePhase::River, cards: vec![board[4]] },
+            shows("a", vec![card(Rank::Ace, Suit::Hearts), card(Rank::King, Suit::Diamonds)]),
+            shows("b", vec![card(Rank::Nine, Suit::Clubs), card(Rank::Nine, Suit::Spades)]),
+            HandEvent::PotAwarded { player: "a".to_string(), amount: 2000, pot: 0 },
+        ]);
+
+        let text = to_pokerstars("Table 1", 6, &hand, Viewer::Spectator);
+        assert!(text.contains("Bob: bets 940 and is all-in"));
+        assert!(text.contains("*** RIVER *** [Th 7c 2s As] [3d]"));
+        assert!(text.contains("Alice: shows [Ah Kd] (a pair of Aces)"));
+        assert!(text.contains("Bob: shows [9c 9s] (a pair of Nines)"));
+        assert!(text.contains("Alice collected 2000 from pot"));
+        assert!(text.contains("Seat 1: Alice (button) showed [Ah Kd] and won (2000) with a pair of Aces"));
+        assert!(text.contains("Seat 2: Bob (big blind) showed [9c 9s] and lost with a pair of Nines"));
+        assert!(!text.contains("Uncalled bet"));
+    }
+}
@@ -2,11 +2,22 @@ mod models;
 mod game_manager;
 mod api;
 mod auth;
+mod bots;
+mod blind_schedule;
+mod cash;
+mod tournament;
+mod scoring;
+mod storage;
+mod hand_history;
+mod duplicate;
 
 use actix_web::{web, App, HttpServer, HttpResponse};
 use actix_files as fs;
 use game_manager::GameManager;
 
+/// Intervalle entre deux points de reprise : une panne fait perdre au plus ce temps de jeu
+const CHECKPOINT_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
 /// Route de santé
 async fn health() -> HttpResponse {
     HttpResponse::Ok().json(serde_json::json!({
@@ -15,19 +26,66 @@ async fn health() -> HttpResponse {
     }))
 }
 
+/// Délai d'archivage des parties terminées (`POKER_FINISHED_GAME_TTL_SECONDS`, 0 = jamais)
+fn finished_game_ttl_from_env() -> Result<Option<std::time::Duration>, String> {
+    let Some(ttl) = std::env::var("POKER_FINISHED_GAME_TTL_SECONDS").ok().filter(|v| !v.trim().is_empty()) else {
+        return Ok(Some(game_manager::DEFAULT_FINISHED_GAME_TTL));
+    };
+    let seconds: u64 = ttl.trim().parse()
+        .map_err(|_| format!("POKER_FINISHED_GAME_TTL_SECONDS invalide: {}", ttl))?;
+    Ok((seconds > 0).then(|| std::time::Duration::from_secs(seconds)))
+}
+
+/// Base SQLite des parties (`POKER_DB_PATH`, défaut `poker.db`)
+fn db_path_from_env() -> String {
+    std::env::var("POKER_DB_PATH").ok()
+        .filter(|v| !v.trim().is_empty())
+        .unwrap_or_else(|| "poker.db".to_string())
+}
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     println!("🎮 Démarrage du serveur de poker...");
+
+    // Clés JWT et durée de validité des tokens (variables POKER_JWT_*)
+    let mut auth_config = auth::AuthConfig::from_env().map_err(std::io::Error::other)?;
+    if auth_config.admin_key.is_none() {
+        // Sans clé configurée, une clé aléatoire valable le temps de cette exécution
+        let admin_key = format!("adm_{}", uuid::Uuid::new_v4().simple());
+        println!("🔐 Aucune clé admin (POKER_ADMIN_KEY) : clé générée pour cette exécution: {}", admin_key);
+        auth_config.admin_key = Some(admin_key);
+    }
+    if auth_config.uses_generated_key() {
+        println!("⚠️ Aucune clé JWT configurée (POKER_JWT_SECRET / POKER_JWT_KEYS) : clé générée pour cette exécution, les tokens ne survivront pas à un redémarrage");
+    }
+    println!("🔑 Tokens JWT valables {} s, clé active: {}", auth_config.ttl.num_seconds(), auth_config.keys[0].kid);
+    auth::init(auth_config);
     
     // Créer le gestionnaire de parties (partagé entre tous les workers)
-    let game_manager = web::Data::new(GameManager::new());
+    let finished_game_ttl = finished_game_ttl_from_env().map_err(std::io::Error::other)?;
+    match finished_game_ttl {
+        Some(ttl) => println!("🗄️  Parties terminées archivées après {} s", ttl.as_secs()),
+        None => println!("🗄️  Archivage automatique des parties terminées désactivé"),
+    }
+    let db_path = db_path_from_env();
+    println!("💾 Parties enregistrées dans {}", db_path);
+    let storage = storage::Storage::open(&db_path).map_err(std::io::Error::other)?;
+    let game_manager = GameManager::with_finished_game_ttl(finished_game_ttl)
+        .with_storage(storage)
+        .map_err(std::io::Error::other)?;
+    let game_manager = web::Data::new(game_manager);
 
-    // Lancer le thread de maintenance (timeouts)
+    // Lancer le thread de maintenance (timeouts chaque seconde, points de reprise moins souvent)
     let manager_clone = game_manager.clone();
     std::thread::spawn(move || {
+        let mut last_checkpoint = std::time::Instant::now();
         loop {
             std::thread::sleep(std::time::Duration::from_secs(1));
             manager_clone.run_maintenance();
+            if last_checkpoint.elapsed() >= CHECKPOINT_INTERVAL {
+                manager_clone.checkpoint();
+                last_checkpoint = std::time::Instant::now();
+            }
         }
     });
 
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use poker_engine::{PlayerAction as EngineAction, GamePhase, Card};
+use crate::blind_schedule::BlindLevel;
+use crate::scoring::default_points_table;
+use crate::cash::{default_max_buy_in_bb, default_min_buy_in_bb};
+use poker_engine::{PlayerAction as EngineAction, GamePhase, Card, HandRank, LoggedEvent, MissedBlindsRule, OddChipRule};
 
 /// Identifiant unique d'une partie
 pub type GameId = Uuid;
@@ -8,6 +11,15 @@ pub type GameId = Uuid;
 /// Identifiant unique d'un joueur/bot
 pub type PlayerId = String;
 
+/// Identifiant unique d'un tournoi multi-tables
+pub type TournamentId = Uuid;
+
+/// Identifiant unique d'un match duplicate
+pub type DuplicateId = Uuid;
+
+/// Identifiant stable d'un bot enregistré (son player_id dans toutes les parties)
+pub type BotId = String;
+
 /// Type de joueur
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -24,6 +36,84 @@ pub struct CreateGameRequest {
     pub starting_chips: u32,
     pub small_blind: u32,
     pub big_blind: u32,
+    #[serde(default)]
+    pub ante: u32,            // Ante par joueur (ou totale si big_blind_ante)
+    #[serde(default)]
+    pub big_blind_ante: bool, // La big blind paie l'ante pour toute la table
+    #[serde(default)]
+    pub straddle: bool,       // Straddle UTG obligatoire
+    #[serde(default)]
+    pub odd_chip_rule: OddChipRule, // Attribution des jetons indivisibles lors d'un partage
+    #[serde(default)]
+    pub missed_blinds: MissedBlindsRule, // Blinds dues au retour d'une absence ou à l'arrivée en cours de partie
+    #[serde(default)]
+    pub late_join: bool, // Accepter des joueurs après le début de la partie
+    #[serde(default)]
+    pub blind_levels: Vec<BlindLevel>, // Structure de blinds de tournoi (vide = blinds fixes)
+    #[serde(default = "default_points_table")]
+    pub points_table: Vec<u32>, // Points attribués à la 1re, 2e... place (défaut: 10/6/4/2/1/0)
+    #[serde(default)]
+    pub cash: Option<CashGameConfig>, // Table de cash game (None = partie à élimination)
+    #[serde(default)]
+    pub seed: Option<u64>, // Graine des paquets : mêmes cartes à chaque partie (None = aléatoire)
+}
+
+impl Default for CreateGameRequest {
+    /// Partie en tête-à-tête, 1000 jetons, blinds 10/20 et barème de points par défaut
+    fn default() -> Self {
+        CreateGameRequest {
+            name: String::new(),
+            max_players: 2,
+            starting_chips: 1000,
+            small_blind: 10,
+            big_blind: 20,
+            ante: 0,
+            big_blind_ante: false,
+            straddle: false,
+            odd_chip_rule: Default::default(),
+            missed_blinds: Default::default(),
+            late_join: false,
+            blind_levels: vec![],
+            points_table: default_points_table(),
+            cash: None,
+            seed: None,
+        }
+    }
+}
+
+/// Paramètres d'une table de cash game
+#[derive(Debug, Deserialize)]
+pub struct CashGameConfig {
+    #[serde(default = "default_min_buy_in_bb")]
+    pub min_buy_in_bb: u32, // Cave minimale, en big blinds
+    #[serde(default = "default_max_buy_in_bb")]
+    pub max_buy_in_bb: u32, // Tapis maximal après une cave ou un complément, en big blinds
+}
+
+/// Type de partie
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameType {
+    Freezeout, // Jusqu'à ce qu'un joueur ait tous les jetons
+    Cash,      // Caves libres, jamais terminée
+}
+
+/// Requête de recave ou de complément de tapis (cash game)
+#[derive(Debug, Deserialize)]
+pub struct BuyChipsRequest {
+    pub amount: u32,
+}
+
+/// Réponse à une recave ou un complément
+#[derive(Debug, Serialize)]
+pub struct BuyChipsResponse {
+    pub chips: u32, // Tapis après l'achat (ajouté à la fin de la main en cours)
+}
+
+/// Réponse au départ d'un joueur
+#[derive(Debug, Serialize)]
+pub struct LeaveGameResponse {
+    pub chips: u32, // Tapis emporté : retiré du jeu, ou encaissé en cash game
 }
 
 /// Réponse après création d'une partie
@@ -36,12 +126,16 @@ pub struct CreateGameResponse {
 /// Requête pour rejoindre une partie
 #[derive(Debug, Deserialize)]
 pub struct JoinGameRequest {
-    pub bot_name: String,
+    #[serde(default)]
+    pub bot_name: String, // Ignoré pour un bot enregistré (son nom de compte est utilisé)
     #[serde(default = "default_player_type")]
     pub player_type: PlayerType,
     #[serde(default)]
-    #[allow(dead_code)] // Pour usage futur (authentification)
-    pub bot_secret: Option<String>,
+    pub bot_id: Option<BotId>,
+    #[serde(default)]
+    pub bot_secret: Option<String>, // Clé d'API du bot enregistré
+    #[serde(default)]
+    pub buy_in: Option<u32>, // Cave en cash game (défaut: starting_chips de la table)
 }
 
 fn default_player_type() -> PlayerType {
@@ -57,6 +151,32 @@ pub struct JoinGameResponse {
     pub auth_token: String,  // Token JWT pour l'authentification
 }
 
+/// Token émis (renouvellement, connexion admin)
+#[derive(Debug, Serialize)]
+pub struct TokenResponse {
+    pub auth_token: String,
+    pub expires_at: i64, // Timestamp d'expiration
+}
+
+impl TokenResponse {
+    pub fn new(auth_token: String) -> Result<Self, String> {
+        let expires_at = crate::auth::verify_token(&auth_token)?.exp;
+        Ok(Self { auth_token, expires_at })
+    }
+}
+
+/// Connexion de l'organisateur
+#[derive(Debug, Deserialize)]
+pub struct AdminLoginRequest {
+    pub admin_key: String,
+}
+
+/// Exclusion d'un joueur par l'organisateur
+#[derive(Debug, Deserialize)]
+pub struct KickPlayerRequest {
+    pub player_id: PlayerId,
+}
+
 /// Action d'un joueur (format API)
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(tag = "type", rename_all = "lowercase")]
@@ -93,6 +213,8 @@ pub struct SubmitActionResponse {
     pub success: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<&'static str>, // Code stable pour les bots (ex: "not_your_turn")
 }
 
 /// Information sur un joueur (pour l'API)
@@ -122,15 +244,114 @@ pub struct GameStateResponse {
     pub your_chips: Option<u32>,
     pub your_cards: Option<Vec<String>>,
     pub valid_actions: Vec<String>,
+    pub small_blind: u32, // Mises forcées de la main en cours
+    pub big_blind: u32,
+    pub ante: u32,
+    pub blind_level: Option<BlindLevelInfo>, // Niveau de la structure de blinds, si définie
+    pub min_raise_to: Option<u32>, // Relance "à" minimum (mise totale du tour)
+    pub max_raise_to: Option<u32>, // Relance "à" maximum (tapis)
+    pub game_type: GameType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cash: Option<CashTableInfo>, // Limites de cave et résultats, en cash game
     pub game_finished: bool,
+    pub paused: bool,  // Partie suspendue par l'organisateur
+    pub aborted: bool, // Partie interrompue : jetons figés, pas de classement
     pub winner_id: Option<PlayerId>,
     pub winner_name: Option<String>,
-    pub action_log: Option<Vec<String>>,
+    pub action_log: Option<Vec<String>>, // Journal lisible, rendu à partir des événements
+    pub hand_events: Vec<LoggedEvent>,   // Événements de la main en cours, cartes privées filtrées
     pub last_hand_winner: Option<PlayerId>,
     pub last_hand_winner_name: Option<String>,
+    pub last_hand_winners: Vec<PlayerId>, // Plusieurs gagnants en cas de pot partagé
     pub last_hand_amount: Option<u32>,
     pub last_hand_description: Option<String>,
-    pub last_hand_cards: Option<Vec<String>>,
+    pub last_hand_cards: Option<Vec<String>>, // Meilleure combinaison de 5 cartes du gagnant
+    pub last_hand_result: Option<HandResultInfo>,
+}
+
+/// Table de cash game : limites de cave et résultats des joueurs
+#[derive(Debug, Serialize)]
+pub struct CashTableInfo {
+    pub min_buy_in: u32,
+    pub max_buy_in: u32,
+    pub results: Vec<CashResult>,
+}
+
+/// Résultat d'un joueur en cash game
+#[derive(Debug, Clone, Serialize)]
+pub struct CashResult {
+    pub player_id: PlayerId,
+    pub name: String,
+    pub bought_in: u32,  // Total des caves, recaves et compléments
+    pub cashed_out: u32, // Jetons emportés en quittant la table
+    pub chips: u32,      // Tapis actuel (0 s'il a quitté la table)
+    pub net_profit: i64,
+}
+
+/// Niveau de blinds en cours
+#[derive(Debug, Serialize)]
+pub struct BlindLevelInfo {
+    pub level: usize,
+    pub small_blind: u32,
+    pub big_blind: u32,
+    pub ante: u32,
+    pub hands_left: Option<u32>,   // Niveau à durée en mains
+    pub seconds_left: Option<u64>, // Niveau à durée en secondes
+}
+
+/// Résultat détaillé d'une main terminée
+#[derive(Debug, Serialize)]
+pub struct HandResultInfo {
+    pub hand_number: u32,
+    pub board: Vec<String>,
+    pub description: String,
+    pub pots: Vec<PotResultInfo>,
+    pub revealed_hands: Vec<ShowdownHandInfo>, // Vide si victoire sans abattage
+    pub events: Vec<LoggedEvent>,                // Déroulement de la main, cartes privées filtrées
+    pub seed: Option<u64>,                       // Graine du paquet, pour rejouer la donne (None si paquet imposé ou partie en cours)
+}
+
+/// Attribution d'un pot (principal puis secondaires)
+#[derive(Debug, Serialize)]
+pub struct PotResultInfo {
+    pub amount: u32,
+    pub winners: Vec<PotWinnerInfo>,
+}
+
+/// Part d'un pot remportée par un joueur
+#[derive(Debug, Serialize)]
+pub struct PotWinnerInfo {
+    pub player_id: PlayerId,
+    pub name: String,
+    pub amount: u32,
+}
+
+/// Main dévoilée à l'abattage
+#[derive(Debug, Serialize)]
+pub struct ShowdownHandInfo {
+    pub player_id: PlayerId,
+    pub name: String,
+    pub hole_cards: Vec<String>,
+    pub rank: HandRank,
+    pub description: String,
+    pub best_five: Vec<String>,
+}
+
+/// Raison de la fin d'une attente (long-poll)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WaitReason {
+    YourTurn,     // Le joueur doit agir
+    HandEnded,    // La main en cours est terminée
+    GameFinished, // La partie est terminée
+    Timeout,      // Délai écoulé sans changement pour le joueur
+}
+
+/// Réponse du long-poll : raison du réveil et état vu par le joueur
+#[derive(Debug, Serialize)]
+pub struct WaitResponse {
+    pub reason: WaitReason,
+    pub state: GameStateResponse,
 }
 
 /// Liste des parties disponibles
@@ -148,12 +369,215 @@ pub struct GameSummary {
     pub max_players: usize,
     pub phase: String,
     pub pot: u32,
+    pub game_type: GameType,
+    pub paused: bool,
+    pub aborted: bool,
+}
+
+/// Partie terminée archivée : la salle est libérée, seuls ses résultats sont conservés
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedGame {
+    pub game_id: GameId,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tournament_id: Option<TournamentId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duplicate_id: Option<DuplicateId>,
+    pub finished_at: i64,
+    pub aborted: bool,
+    pub hands_played: u32,
+    pub winner_name: Option<String>,
+    pub standings: Vec<FinishingPosition>,
+}
+
+/// Liste des parties archivées
+#[derive(Debug, Serialize)]
+pub struct ArchivedGameListResponse {
+    pub games: Vec<ArchivedGame>,
+}
+
+/// Requête pour créer un tournoi multi-tables
+#[derive(Debug, Deserialize)]
+pub struct CreateTournamentRequest {
+    pub name: String,
+    pub table_size: usize, // Nombre de joueurs maximum par table
+    pub starting_chips: u32,
+    pub small_blind: u32,
+    pub big_blind: u32,
+    #[serde(default)]
+    pub ante: u32,
+    #[serde(default)]
+    pub blind_levels: Vec<BlindLevel>, // Structure de blinds commune à toutes les tables
+    #[serde(default = "default_points_table")]
+    pub points_table: Vec<u32>,
+}
+
+/// Réponse après création d'un tournoi
+#[derive(Debug, Serialize)]
+pub struct CreateTournamentResponse {
+    pub tournament_id: TournamentId,
+    pub name: String,
+}
+
+/// Réponse après inscription à un tournoi
+#[derive(Debug, Serialize)]
+pub struct TournamentRegistrationResponse {
+    pub player_id: PlayerId,
+    pub tournament_id: TournamentId,
+    pub auth_token: String, // Valable sur toutes les tables du tournoi
+}
+
+/// État d'un tournoi
+#[derive(Debug, Serialize)]
+pub struct TournamentStatusResponse {
+    pub tournament_id: TournamentId,
+    pub name: String,
+    pub started: bool,
+    pub finished: bool,
+    pub players_registered: usize,
+    pub players_remaining: usize,
+    pub tables: Vec<TournamentTableInfo>,
+    pub final_table: Option<GameId>,
+    pub finishing_positions: Vec<FinishingPosition>, // Joueurs sortis (et vainqueur), du 1er au dernier
+}
+
+/// Table d'un tournoi et joueurs qui y sont assis
+#[derive(Debug, Serialize)]
+pub struct TournamentTableInfo {
+    pub game_id: GameId,
+    pub name: String,
+    pub players: Vec<PlayerId>, // Joueurs encore en lice rattachés à cette table (arrivées comprises)
+}
+
+/// Place finale d'un joueur
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinishingPosition {
+    pub position: usize,
+    pub player_id: PlayerId,
+    pub name: String,
+    pub points: u32, // Selon le barème de la partie ou du tournoi
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<BotId>,
+}
+
+/// Classement d'un tournoi
+#[derive(Debug, Serialize)]
+pub struct TournamentStandingsResponse {
+    pub tournament_id: TournamentId,
+    pub name: String,
+    pub finished: bool,
+    pub standings: Vec<FinishingPosition>, // Places connues, de la meilleure à la moins bonne
+}
+
+/// Requête pour créer un match duplicate
+#[derive(Debug, Deserialize)]
+pub struct CreateDuplicateRequest {
+    pub name: String,
+    pub starting_chips: u32,
+    pub small_blind: u32,
+    pub big_blind: u32,
+    #[serde(default)]
+    pub ante: u32,
+    pub hands: u32,        // Mains jouées à chaque table
+    #[serde(default)]
+    pub seed: Option<u64>, // Graine commune des paquets (None = tirée au hasard)
+}
+
+/// Réponse après création d'un match duplicate
+#[derive(Debug, Serialize)]
+pub struct CreateDuplicateResponse {
+    pub duplicate_id: DuplicateId,
+    pub name: String,
+}
+
+/// Réponse après inscription à un match duplicate
+#[derive(Debug, Serialize)]
+pub struct DuplicateRegistrationResponse {
+    pub player_id: PlayerId,
+    pub duplicate_id: DuplicateId,
+    pub auth_token: String, // Valable à la table en jeu du match
+}
+
+/// État d'un match duplicate
+#[derive(Debug, Serialize)]
+pub struct DuplicateStatusResponse {
+    pub duplicate_id: DuplicateId,
+    pub name: String,
+    pub hands: u32,
+    pub seed: Option<u64>, // Dévoilée une fois le match terminé
+    pub started: bool,
+    pub finished: bool,
+    pub tables: Vec<DuplicateTableInfo>, // Tables déjà ouvertes, la dernière étant celle en jeu
+    pub standings: Vec<DuplicateStanding>, // Du meilleur écart de jetons au moins bon
+}
+
+/// Table d'un match duplicate
+#[derive(Debug, Serialize)]
+pub struct DuplicateTableInfo {
+    pub game_id: GameId,
+    pub name: String,
+    pub seats: Vec<PlayerId>, // Joueurs dans l'ordre des sièges
+    pub hands_played: u32,
+    pub finished: bool,
+}
+
+/// Résultat d'un joueur sur l'ensemble des tables d'un match duplicate
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateStanding {
+    pub player_id: PlayerId,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<BotId>,
+    pub chip_differential: i64,        // Somme des gains et pertes de jetons sur toutes les tables
+    pub table_differentials: Vec<i64>, // Gain ou perte sur chaque table, dans l'ordre des tables
+}
+
+/// Ligne du classement général
+#[derive(Debug, Clone, Serialize)]
+pub struct LeaderboardEntry {
+    pub rank: usize,
+    pub name: String, // Nom du bot (résultats regroupés par bot_id, ou par nom pour un invité)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<BotId>,
+    pub points: u32,
+    pub games_played: u32,
+    pub wins: u32,
+    pub best_position: usize,
+}
+
+/// Classement général, toutes parties et tous tournois terminés confondus
+#[derive(Debug, Serialize)]
+pub struct LeaderboardResponse {
+    pub games_counted: usize,
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+/// Requête d'enregistrement d'un bot
+#[derive(Debug, Deserialize)]
+pub struct RegisterBotRequest {
+    pub name: String,
+}
+
+/// Réponse à l'enregistrement : la clé d'API n'est communiquée qu'ici
+#[derive(Debug, Serialize)]
+pub struct RegisterBotResponse {
+    pub bot_id: BotId,
+    pub name: String,
+    pub api_key: String,
+}
+
+/// Profil public d'un bot enregistré
+#[derive(Debug, Serialize)]
+pub struct BotProfileResponse {
+    pub bot_id: BotId,
+    pub name: String,
+    pub created_at: i64,
+    pub stats: Option<LeaderboardEntry>, // Absent tant qu'aucune partie n'est terminée
 }
 
 /// Événement WebSocket pour les mises à jour en temps réel
 #[derive(Debug, Serialize, Clone)]
 #[serde(tag = "type", rename_all = "snake_case")]
-#[allow(dead_code)] // Pour usage futur (WebSocket)
 pub enum GameEvent {
     GameUpdate {
         game_id: GameId,
@@ -179,6 +603,58 @@ pub enum GameEvent {
         game_id: GameId,
         winner: String,
     },
+    GamePaused {
+        game_id: GameId,
+    },
+    GameResumed {
+        game_id: GameId,
+    },
+    GameAborted {
+        game_id: GameId,
+    },
+    /// Privé : envoyé uniquement au joueur qui doit agir
+    YourTurn {
+        game_id: GameId,
+        player_id: PlayerId,
+        hole_cards: Vec<String>,
+        chips: u32,
+        to_call: u32,
+        valid_actions: Vec<String>,
+        min_raise_to: Option<u32>,
+        max_raise_to: Option<u32>,
+    },
+}
+
+/// Événement numéroté (identifiant croissant par partie, utilisé pour la reprise SSE)
+#[derive(Debug, Serialize, Clone)]
+pub struct SequencedEvent {
+    pub id: u64,
+    pub event: GameEvent,
+}
+
+impl GameEvent {
+    /// Nom de l'événement (champ `type` du JSON)
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameEvent::GameUpdate { .. } => "game_update",
+            GameEvent::PlayerAction { .. } => "player_action",
+            GameEvent::PlayerJoined { .. } => "player_joined",
+            GameEvent::GameStarted { .. } => "game_started",
+            GameEvent::GameEnded { .. } => "game_ended",
+            GameEvent::GamePaused { .. } => "game_paused",
+            GameEvent::GameResumed { .. } => "game_resumed",
+            GameEvent::GameAborted { .. } => "game_aborted",
+            GameEvent::YourTurn { .. } => "your_turn",
+        }
+    }
+
+    /// L'événement peut-il être envoyé à ce joueur ?
+    pub fn is_visible_to(&self, player_id: &PlayerId) -> bool {
+        match self {
+            GameEvent::YourTurn { player_id: recipient, .. } => recipient == player_id,
+            _ => true,
+        }
+    }
 }
 
 /// Convertir une carte en string pour l'API
@@ -186,6 +662,17 @@ pub fn card_to_string(card: &Card) -> String {
     format!("{}", card)
 }
 
+/// Convertir une action du moteur en string pour l'API
+pub fn action_to_string(action: &EngineAction) -> String {
+    match action {
+        EngineAction::Fold => "fold".to_string(),
+        EngineAction::Check => "check".to_string(),
+        EngineAction::Call => "call".to_string(),
+        EngineAction::Raise(_) => "raise".to_string(),
+        EngineAction::AllIn => "allin".to_string(),
+    }
+}
+
 /// Convertir une phase en string
 pub fn phase_to_string(phase: &GamePhase) -> String {
     match phase {
@@ -0,0 +1,117 @@
+use crate::models::{FinishingPosition, LeaderboardEntry};
+
+/// Barème de points par défaut (voir docs/TOURNAMENT.md)
+pub const DEFAULT_POINTS_TABLE: [u32; 6] = [10, 6, 4, 2, 1, 0];
+
+pub fn default_points_table() -> Vec<u32> {
+    DEFAULT_POINTS_TABLE.to_vec()
+}
+
+/// Points attribués pour une place (à partir de 1), 0 au-delà du barème
+pub fn points_for(points_table: &[u32], position: usize) -> u32 {
+    position
+        .checked_sub(1)
+        .and_then(|i| points_table.get(i))
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Classement général : cumul des points de chaque partie, regroupés par bot
+///
+/// Un bot enregistré est identifié par son `bot_id`, un invité par son nom.
+///
+/// `results` contient les places finales de chaque partie terminée.
+pub fn leaderboard<'a>(results: impl IntoIterator<Item = &'a [FinishingPosition]>) -> Vec<LeaderboardEntry> {
+    let mut entries: Vec<LeaderboardEntry> = Vec::new();
+
+    for game in results {
+        for finish in game {
+            let same_bot = |e: &LeaderboardEntry| match &finish.bot_id {
+                Some(bot_id) => e.bot_id.as_ref() == Some(bot_id),
+                None => e.bot_id.is_none() && e.name == finish.name,
+            };
+            let entry = match entries.iter().position(same_bot) {
+                Some(i) => &mut entries[i],
+                None => {
+                    entries.push(LeaderboardEntry {
+                        rank: 0,
+                        name: finish.name.clone(),
+                        bot_id: finish.bot_id.clone(),
+                        points: 0,
+                        games_played: 0,
+                        wins: 0,
+                        best_position: finish.position,
+                    });
+                    entries.last_mut().unwrap()
+                }
+            };
+            entry.points += finish.points;
+            entry.games_played += 1;
+            entry.wins += u32::from(finish.position == 1);
+            entry.best_position = entry.best_position.min(finish.position);
+        }
+    }
+
+    // Points, puis victoires, puis ordre alphabétique pour départager
+    entries.sort_by(|a, b| {
+        b.points.cmp(&a.points)
+            .then(b.wins.cmp(&a.wins))
+            .then(a.name.cmp(&b.name))
+    });
+    for (i, entry) in entries.iter_mut().enumerate() {
+        entry.rank = i + 1;
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finish(name: &str, position: usize) -> FinishingPosition {
+        FinishingPosition {
+            position,
+            player_id: format!("{}_id", name),
+            name: name.to_string(),
+            points: points_for(&DEFAULT_POINTS_TABLE, position),
+            bot_id: None,
+        }
+    }
+
+    #[test]
+    fn test_points_for_position() {
+        assert_eq!(points_for(&DEFAULT_POINTS_TABLE, 1), 10);
+        assert_eq!(points_for(&DEFAULT_POINTS_TABLE, 4), 2);
+        assert_eq!(points_for(&DEFAULT_POINTS_TABLE, 9), 0);
+        assert_eq!(points_for(&[5, 3], 0), 0);
+    }
+
+    #[test]
+    fn test_leaderboard_aggregates_by_bot_name() {
+        // Grille d'exemple de docs/TOURNAMENT.md
+        let game_1 = vec![finish("Bot A", 1), finish("Bot B", 2), finish("Bot C", 3), finish("Bot D", 4)];
+        let game_2 = vec![finish("Bot B", 1), finish("Bot D", 2), finish("Bot A", 3), finish("Bot C", 4)];
+        let game_3 = vec![finish("Bot C", 1), finish("Bot A", 2), finish("Bot D", 3), finish("Bot B", 4)];
+
+        let board = leaderboard([game_1.as_slice(), game_2.as_slice(), game_3.as_slice()]);
+
+        let summary: Vec<_> = board.iter().map(|e| (e.rank, e.name.as_str(), e.points)).collect();
+        assert_eq!(summary, vec![(1, "Bot A", 20), (2, "Bot B", 18), (3, "Bot C", 16), (4, "Bot D", 12)]);
+        assert!(board.iter().all(|e| e.games_played == 3));
+        assert_eq!(board.iter().map(|e| e.wins).collect::<Vec<_>>(), vec![1, 1, 1, 0]);
+        assert_eq!(board[3].best_position, 2);
+    }
+
+    #[test]
+    fn test_registered_bots_are_grouped_by_id() {
+        let registered = |position| FinishingPosition { bot_id: Some("Bot A_id".to_string()), ..finish("Bot A", position) };
+        let game_1 = vec![registered(1), finish("Bot A", 2)];
+        let game_2 = vec![finish("Bot B", 1), registered(2)];
+
+        let board = leaderboard([game_1.as_slice(), game_2.as_slice()]);
+
+        // Le bot enregistré et l'invité homonyme d'une ancienne partie restent distincts
+        let summary: Vec<_> = board.iter().map(|e| (e.name.as_str(), e.bot_id.is_some(), e.points)).collect();
+        assert_eq!(summary, vec![("Bot A", true, 16), ("Bot B", false, 10), ("Bot A", false, 6)]);
+    }
+}
@@ -0,0 +1,709 @@
+use crate::blind_schedule::BlindSchedule;
+use crate::bots::BotAccount;
+use crate::cash::CashTable;
+use crate::duplicate::DuplicateMatch;
+use crate::game_manager::GameRoom;
+use crate::models::*;
+use crate::tournament::Tournament;
+use poker_engine::{GameState, HandResult};
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS bots (
+        bot_id       TEXT PRIMARY KEY,
+        name         TEXT NOT NULL,
+        created_at   INTEGER NOT NULL,
+        salt         BLOB NOT NULL,
+        api_key_hash BLOB NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS games (
+        game_id       TEXT PRIMARY KEY,
+        name          TEXT NOT NULL,
+        tournament_id TEXT,
+        config        TEXT NOT NULL, -- Paramètres fixés à la création (JSON)
+        progress      TEXT NOT NULL  -- Déroulement de la partie, état du moteur compris (JSON)
+    );
+    CREATE TABLE IF NOT EXISTS seats (
+        game_id        TEXT NOT NULL,
+        player_id      TEXT NOT NULL,
+        name           TEXT NOT NULL,
+        player_type    TEXT NOT NULL,
+        registered_bot INTEGER NOT NULL,
+        PRIMARY KEY (game_id, player_id)
+    );
+    CREATE TABLE IF NOT EXISTS hands (
+        game_id        TEXT NOT NULL,
+        hand_number    INTEGER NOT NULL,
+        board          TEXT NOT NULL,
+        events         TEXT NOT NULL,
+        pots           TEXT NOT NULL,
+        revealed_hands TEXT NOT NULL,
+        seed           INTEGER,
+        PRIMARY KEY (game_id, hand_number)
+    );
+    CREATE TABLE IF NOT EXISTS tournaments (
+        tournament_id TEXT PRIMARY KEY,
+        record        TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS duplicates (
+        duplicate_id TEXT PRIMARY KEY,
+        record       TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS archived_games (
+        game_id TEXT PRIMARY KEY,
+        record  TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS checkpoint (
+        id       INTEGER PRIMARY KEY CHECK (id = 1),
+        saved_at INTEGER NOT NULL -- Dernier enregistrement, pour mesurer l'interruption du serveur
+    );
+";
+
+/// Paramètres d'une partie, fixés à sa création
+#[derive(Serialize, Deserialize)]
+struct GameConfig {
+    max_players: usize,
+    starting_chips: u32,
+    late_join: bool,
+    points_table: Vec<u32>,
+    #[serde(default)]
+    duplicate_id: Option<DuplicateId>,
+}
+
+/// Déroulement d'une partie : tout ce qui permet de la reprendre là où elle en était
+#[derive(Serialize, Deserialize)]
+struct GameProgress {
+    state: GameState,
+    started: bool,
+    game_finished: bool,
+    paused_at: Option<SystemTime>,
+    aborted: bool,
+    finished_at: Option<SystemTime>,
+    winner_id: Option<PlayerId>,
+    player_timeout_strikes: HashMap<PlayerId, u32>,
+    blind_schedule: Option<BlindSchedule>,
+    cash: Option<CashTable>,
+    finishing_positions: Vec<(PlayerId, usize)>,
+    last_event_id: u64,
+}
+
+/// Joueur passé à une table (assis ou reparti)
+#[derive(Serialize)]
+struct SeatRecord {
+    player_id: PlayerId,
+    name: String,
+    player_type: PlayerType,
+    registered_bot: bool,
+}
+
+/// Données restaurées au démarrage
+pub struct SavedData {
+    pub bots: Vec<BotAccount>,
+    pub games: Vec<GameRoom>,
+    pub tournaments: Vec<Tournament>,
+    pub duplicates: Vec<DuplicateMatch>,
+    pub archive: Vec<ArchivedGame>,
+}
+
+/// Stockage SQLite des parties, tournois, matchs duplicate, mains jouées et comptes de bots
+///
+/// Le gestionnaire de parties enregistre régulièrement un point de reprise ; seules les
+/// parties, tournois et matchs modifiés depuis le précédent sont réécrits, les mains terminées
+/// sont ajoutées au fur et à mesure.
+pub struct Storage {
+    inner: Mutex<Inner>,
+}
+
+struct Inner {
+    conn: Connection,
+    digests: HashMap<String, Vec<u8>>,    // Empreinte du dernier enregistrement de chaque partie, tournoi et match
+    saved_hands: HashMap<GameId, u32>,    // Dernière main enregistrée par partie
+}
+
+impl Storage {
+    /// Ouvrir (ou créer) la base de données
+    pub fn open(path: &str) -> Result<Self, String> {
+        let conn = Connection::open(path).map_err(db_error)?;
+        conn.execute_batch("PRAGMA journal_mode = WAL; PRAGMA synchronous = NORMAL;")
+            .map_err(db_error)?;
+        conn.execute_batch(SCHEMA).map_err(db_error)?;
+
+        Ok(Self {
+            inner: Mutex::new(Inner {
+                conn,
+                digests: HashMap::new(),
+                saved_hands: HashMap::new(),
+            }),
+        })
+    }
+
+    /// Enregistrer un compte de bot (dès sa création : sa clé d'API n'est communiquée qu'une fois)
+    pub fn save_bot(&self, account: &BotAccount) -> Result<(), String> {
+        let (salt, api_key_hash) = account.credentials();
+        self.inner.lock().unwrap().conn.execute(
+            "INSERT OR REPLACE INTO bots (bot_id, name, created_at, salt, api_key_hash) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![account.bot_id, account.name, account.created_at, salt, api_key_hash],
+        ).map_err(db_error)?;
+        Ok(())
+    }
+
+    /// Relever un point de reprise : parties, tournois et matchs, nouvelles mains terminées
+    ///
+    /// Appelé sous les verrous du gestionnaire, qui peuvent être relâchés avant l'enregistrement :
+    /// seul l'état est copié ici, la sérialisation et l'écriture se font dans `Checkpoint::save`.
+    pub fn snapshot(
+        &self,
+        tournaments: &HashMap<TournamentId, Tournament>,
+        duplicates: &HashMap<DuplicateId, DuplicateMatch>,
+        games: &HashMap<GameId, GameRoom>,
+    ) -> Checkpoint<'_> {
+        let inner = self.inner.lock().unwrap();
+        let games = games.values()
+            .map(|game| {
+                let saved = inner.saved_hands.get(&game.id).copied().unwrap_or(0);
+                GameSnapshot {
+                    game_id: game.id,
+                    name: game.name.clone(),
+                    tournament_id: game.tournament_id,
+                    config: GameConfig {
+                        max_players: game.max_players,
+                        starting_chips: game.starting_chips,
+                        late_join: game.late_join,
+                        points_table: game.points_table.clone(),
+                        duplicate_id: game.duplicate_id,
+                    },
+                    progress: GameProgress {
+                        state: game.state.clone(),
+                        started: game.started,
+                        game_finished: game.game_finished,
+                        paused_at: game.paused_at,
+                        aborted: game.aborted,
+                        finished_at: game.finished_at,
+                        winner_id: game.winner_id.clone(),
+                        player_timeout_strikes: game.player_timeout_strikes.clone(),
+                        blind_schedule: game.blind_schedule.clone(),
+                        cash: game.cash.clone(),
+                        finishing_positions: game.finishing_positions.clone(),
+                        last_event_id: game.last_event_id,
+                    },
+                    seats: game.player_names.iter()
+                        .map(|(player_id, name)| SeatRecord {
+                            player_id: player_id.clone(),
+                            name: name.clone(),
+                            player_type: game.player_types.get(player_id).copied().unwrap_or(PlayerType::Bot),
+                            registered_bot: game.registered_bots.contains(player_id),
+                        })
+                        .collect(),
+                    hands: game.hand_history.iter().filter(|hand| hand.hand_number > saved).cloned().collect(),
+                }
+            })
+            .collect();
+
+        Checkpoint {
+            inner,
+            tournaments: tournaments.values().cloned().collect(),
+            duplicates: duplicates.values().cloned().collect(),
+            games,
+        }
+    }
+
+    /// Archiver une partie : ses résultats et ses mains sont conservés, la salle est libérée
+    pub fn archive_game(&self, archived: &ArchivedGame) -> Result<(), String> {
+        let mut inner = self.inner.lock().unwrap();
+        let tx = inner.conn.transaction().map_err(db_error)?;
+        let game_id = archived.game_id.to_string();
+        tx.execute(
+            "INSERT OR REPLACE INTO archived_games (game_id, record) VALUES (?1, ?2)",
+            params![game_id, to_json(archived)?],
+        ).map_err(db_error)?;
+        tx.execute("DELETE FROM games WHERE game_id = ?1", params![game_id]).map_err(db_error)?;
+        tx.execute("DELETE FROM seats WHERE game_id = ?1", params![game_id]).map_err(db_error)?;
+        tx.commit().map_err(db_error)?;
+
+        inner.digests.remove(&format!("game:{}", archived.game_id));
+        Ok(())
+    }
+
+    /// Supprimer une partie, archivée ou non, et toutes ses mains
+    pub fn delete_game(&self, game_id: GameId) -> Result<(), String> {
+        let mut inner = self.inner.lock().unwrap();
+        let tx = inner.conn.transaction().map_err(db_error)?;
+        for table in ["games", "seats", "hands", "archived_games"] {
+            tx.execute(&format!("DELETE FROM {} WHERE game_id = ?1", table), params![game_id.to_string()])
+                .map_err(db_error)?;
+        }
+        tx.commit().map_err(db_error)?;
+
+        inner.digests.remove(&format!("game:{}", game_id));
+        inner.saved_hands.remove(&game_id);
+        Ok(())
+    }
+
+    /// Charger tout ce qui a été enregistré (au démarrage)
+    ///
+    /// Le temps écoulé depuis le dernier point de reprise ne compte pas dans les niveaux de
+    /// blinds : une interruption du serveur est traitée comme une pause.
+    pub fn load(&self) -> Result<SavedData, String> {
+        let mut inner = self.inner.lock().unwrap();
+        let conn = &inner.conn;
+
+        let saved_at: Option<i64> = conn.query_row("SELECT saved_at FROM checkpoint WHERE id = 1", [], |row| row.get(0))
+            .optional()
+            .map_err(db_error)?;
+        let downtime = saved_at
+            .map(|saved_at| Duration::from_secs((unix_time(SystemTime::now()) - saved_at).max(0) as u64))
+            .unwrap_or_default();
+
+        let bots = query(conn, "SELECT bot_id, name, created_at, salt, api_key_hash FROM bots", |row| {
+            Ok(BotAccount::restore(row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?))
+        })?;
+
+        let tournaments = query(conn, "SELECT record FROM tournaments", |row| row.get::<_, String>(0))?
+            .iter()
+            .map(|record| from_json(record))
+            .collect::<Result<Vec<Tournament>, String>>()?;
+
+        let duplicates = query(conn, "SELECT record FROM duplicates", |row| row.get::<_, String>(0))?
+            .iter()
+            .map(|record| from_json(record))
+            .collect::<Result<Vec<DuplicateMatch>, String>>()?;
+
+        let archive = query(conn, "SELECT record FROM archived_games", |row| row.get::<_, String>(0))?
+            .iter()
+            .map(|record| from_json(record))
+            .collect::<Result<Vec<ArchivedGame>, String>>()?;
+
+        let rows = query(conn, "SELECT game_id, name, tournament_id, config, progress FROM games", |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, Option<String>>(2)?, row.get::<_, String>(3)?, row.get::<_, String>(4)?))
+        })?;
+        let mut games = Vec::with_capacity(rows.len());
+        for (game_id, name, tournament_id, config, progress) in rows {
+            let game_id = parse_id(&game_id)?;
+            let mut room = restore_room(game_id, name, &from_json(&config)?, from_json(&progress)?, downtime);
+            room.tournament_id = tournament_id.as_deref().map(parse_id).transpose()?;
+            load_seats(conn, &mut room)?;
+            room.hand_history = load_hands(conn, game_id)?;
+            games.push(room);
+        }
+
+        inner.saved_hands = games.iter()
+            .filter_map(|room| room.hand_history.last().map(|hand| (room.id, hand.hand_number)))
+            .collect();
+        Ok(SavedData { bots, games, tournaments, duplicates, archive })
+    }
+}
+
+/// Point de reprise relevé par `Storage::snapshot`, en attente d'enregistrement
+///
+/// La base reste réservée jusqu'à l'enregistrement : une partie archivée ou supprimée entre-temps
+/// ne peut pas être réécrite.
+pub struct Checkpoint<'a> {
+    inner: MutexGuard<'a, Inner>,
+    tournaments: Vec<Tournament>,
+    duplicates: Vec<DuplicateMatch>,
+    games: Vec<GameSnapshot>,
+}
+
+/// Copie d'une partie au moment du point de reprise
+struct GameSnapshot {
+    game_id: GameId,
+    name: String,
+    tournament_id: Option<TournamentId>,
+    config: GameConfig,
+    progress: GameProgress,
+    seats: Vec<SeatRecord>,
+    hands: Vec<HandResult>, // Mains terminées pas encore enregistrées
+}
+
+impl Checkpoint<'_> {
+    /// Enregistrer le point de reprise : seuls les parties, tournois et matchs modifiés depuis
+    /// le précédent sont réécrits
+    ///
+    /// Tout est écrit dans une seule transaction, pour qu'un tournoi ou un match et ses tables restent cohérents.
+    pub fn save(mut self) -> Result<(), String> {
+        let Inner { conn, digests, saved_hands } = &mut *self.inner;
+        let tx = conn.transaction().map_err(db_error)?;
+        let mut new_digests = Vec::new();
+        let mut new_hands = Vec::new();
+
+        for tournament in &self.tournaments {
+            let record = to_json(tournament)?;
+            let key = format!("tournament:{}", tournament.id);
+            let digest = digest_of(&[&record]);
+            if digests.get(&key) != Some(&digest) {
+                tx.execute(
+                    "INSERT OR REPLACE INTO tournaments (tournament_id, record) VALUES (?1, ?2)",
+                    params![tournament.id.to_string(), record],
+                ).map_err(db_error)?;
+                new_digests.push((key, digest));
+            }
+        }
+
+        for duplicate in &self.duplicates {
+            let record = to_json(duplicate)?;
+            let key = format!("duplicate:{}", duplicate.id);
+            let digest = digest_of(&[&record]);
+            if digests.get(&key) != Some(&digest) {
+                tx.execute(
+                    "INSERT OR REPLACE INTO duplicates (duplicate_id, record) VALUES (?1, ?2)",
+                    params![duplicate.id.to_string(), record],
+                ).map_err(db_error)?;
+                new_digests.push((key, digest));
+            }
+        }
+
+        for game in &self.games {
+            let config = to_json(&game.config)?;
+            let progress = to_json(&game.progress)?;
+            let key = format!("game:{}", game.game_id);
+            let digest = digest_of(&[&config, &progress, &to_json(&game.seats)?]);
+            if digests.get(&key) != Some(&digest) {
+                save_game(&tx, game, &config, &progress)?;
+                new_digests.push((key, digest));
+            }
+
+            for hand in &game.hands {
+                save_hand(&tx, game.game_id, hand)?;
+            }
+            if let Some(last) = game.hands.last() {
+                new_hands.push((game.game_id, last.hand_number));
+            }
+        }
+
+        tx.execute(
+            "INSERT OR REPLACE INTO checkpoint (id, saved_at) VALUES (1, ?1)",
+            params![unix_time(SystemTime::now())],
+        ).map_err(db_error)?;
+        tx.commit().map_err(db_error)?;
+
+        digests.extend(new_digests);
+        saved_hands.extend(new_hands);
+        Ok(())
+    }
+}
+
+fn save_game(tx: &Transaction, game: &GameSnapshot, config: &str, progress: &str) -> Result<(), String> {
+    let game_id = game.game_id.to_string();
+    tx.execute(
+        "INSERT OR REPLACE INTO games (game_id, name, tournament_id, config, progress) VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![game_id, game.name, game.tournament_id.map(|id| id.to_string()), config, progress],
+    ).map_err(db_error)?;
+
+    tx.execute("DELETE FROM seats WHERE game_id = ?1", params![game_id]).map_err(db_error)?;
+    for seat in &game.seats {
+        tx.execute(
+            "INSERT INTO seats (game_id, player_id, name, player_type, registered_bot) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![game_id, seat.player_id, seat.name, to_json(&seat.player_type)?, seat.registered_bot],
+        ).map_err(db_error)?;
+    }
+    Ok(())
+}
+
+fn save_hand(tx: &Transaction, game_id: GameId, hand: &HandResult) -> Result<(), String> {
+    tx.execute(
+        "INSERT OR REPLACE INTO hands (game_id, hand_number, board, events, pots, revealed_hands, seed) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            game_id.to_string(),
+            hand.hand_number,
+            to_json(&hand.board)?,
+            to_json(&hand.events)?,
+            to_json(&hand.pots)?,
+            to_json(&hand.revealed_hands)?,
+            hand.seed.map(|seed| seed as i64), // SQLite ne stocke que des entiers signés
+        ],
+    ).map_err(db_error)?;
+    Ok(())
+}
+
+/// Recréer une salle de jeu : les joueurs retrouvent leur place et la main en cours
+fn restore_room(game_id: GameId, name: String, config: &GameConfig, progress: GameProgress, downtime: Duration) -> GameRoom {
+    let mut room = GameRoom::new(game_id, name, config.max_players, config.starting_chips, progress.state.blinds());
+    room.late_join = config.late_join;
+    room.points_table = config.points_table.clone();
+    room.duplicate_id = config.duplicate_id;
+
+    room.state = progress.state;
+    room.started = progress.started;
+    room.game_finished = progress.game_finished;
+    room.paused_at = progress.paused_at;
+    room.aborted = progress.aborted;
+    room.finished_at = progress.finished_at;
+    room.winner_id = progress.winner_id;
+    room.player_timeout_strikes = progress.player_timeout_strikes;
+    room.blind_schedule = progress.blind_schedule;
+    room.cash = progress.cash;
+    room.finishing_positions = progress.finishing_positions;
+    room.last_event_id = progress.last_event_id;
+
+    // L'interruption du serveur compte comme une pause pour le niveau de blinds (une partie
+    // en pause rattrape ce temps à la reprise) ; le joueur qui a la parole repart avec un
+    // temps complet
+    if !room.is_paused() {
+        if let Some(schedule) = room.blind_schedule.as_mut() {
+            schedule.extend_level(downtime);
+        }
+    }
+    room
+}
+
+fn load_seats(conn: &Connection, room: &mut GameRoom) -> Result<(), String> {
+    let mut statement = conn.prepare("SELECT player_id, name, player_type, registered_bot FROM seats WHERE game_id = ?1")
+        .map_err(db_error)?;
+    let seats = statement.query_map(params![room.id.to_string()], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?, row.get::<_, bool>(3)?))
+    }).map_err(db_error)?;
+
+    for seat in seats {
+        let (player_id, name, player_type, registered_bot) = seat.map_err(db_error)?;
+        room.player_types.insert(player_id.clone(), from_json(&player_type)?);
+        if registered_bot {
+            room.registered_bots.insert(player_id.clone());
+        }
+        room.player_names.insert(player_id, name);
+    }
+    Ok(())
+}
+
+fn load_hands(conn: &Connection, game_id: GameId) -> Result<Vec<HandResult>, String> {
+    let mut statement = conn.prepare(
+        "SELECT hand_number, board, events, pots, revealed_hands, seed FROM hands WHERE game_id = ?1 ORDER BY hand_number",
+    ).map_err(db_error)?;
+    let rows = statement.query_map(params![game_id.to_string()], |row| {
+        Ok((row.get::<_, u32>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?, row.get::<_, String>(3)?, row.get::<_, String>(4)?,
+            row.get::<_, Option<i64>>(5)?))
+    }).map_err(db_error)?;
+
+    rows.map(|row| {
+        let (hand_number, board, events, pots, revealed_hands, seed) = row.map_err(db_error)?;
+        Ok(HandResult {
+            hand_number,
+            board: from_json(&board)?,
+            pots: from_json(&pots)?,
+            revealed_hands: from_json(&revealed_hands)?,
+            events: from_json(&events)?,
+            seed: seed.map(|seed| seed as u64),
+        })
+    }).collect()
+}
+
+fn query<T>(conn: &Connection, sql: &str, map: impl FnMut(&rusqlite::Row) -> rusqlite::Result<T>) -> Result<Vec<T>, String> {
+    let mut statement = conn.prepare(sql).map_err(db_error)?;
+    let rows = statement.query_map([], map).map_err(db_error)?;
+    rows.collect::<rusqlite::Result<Vec<T>>>().map_err(db_error)
+}
+
+/// Empreinte SHA-256 d'un enregistrement (stable d'une version de Rust à l'autre)
+fn digest_of(parts: &[&str]) -> Vec<u8> {
+    let mut context = ring::digest::Context::new(&ring::digest::SHA256);
+    for part in parts {
+        // Longueur en préfixe : deux découpages différents ne donnent pas la même empreinte
+        context.update(&(part.len() as u64).to_le_bytes());
+        context.update(part.as_bytes());
+    }
+    context.finish().as_ref().to_vec()
+}
+
+fn unix_time(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH).map(|d| d.as_secs() as i64).unwrap_or(0)
+}
+
+fn parse_id(id: &str) -> Result<Uuid, String> {
+    Uuid::parse_str(id).map_err(|e| format!("Identifiant enregistré invalide {}: {}", id, e))
+}
+
+fn to_json<T: Serialize + ?Sized>(value: &T) -> Result<String, String> {
+    serde_json::to_string(value).map_err(|e| format!("Erreur de sérialisation: {}", e))
+}
+
+fn from_json<T: for<'de> Deserialize<'de>>(json: &str) -> Result<T, String> {
+    serde_json::from_str(json).map_err(|e| format!("Enregistrement illisible: {}", e))
+}
+
+fn db_error(e: rusqlite::Error) -> String {
+    format!("Erreur de base de données: {}", e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_manager::GameManager;
+    use poker_engine::HandEvent;
+
+    /// Base temporaire, supprimée en fin de test
+    struct TempDb(String);
+
+    impl TempDb {
+        fn new() -> Self {
+            let path = std::env::temp_dir().join(format!("poker-test-{}.db", Uuid::new_v4()));
+            TempDb(path.to_string_lossy().into_owned())
+        }
+
+        /// Gestionnaire repris depuis la base, comme après un redémarrage du serveur
+        fn manager(&self) -> GameManager {
+            GameManager::new().with_storage(Storage::open(&self.0).unwrap()).unwrap()
+        }
+    }
+
+    impl Drop for TempDb {
+        fn drop(&mut self) {
+            for suffix in ["", "-wal", "-shm"] {
+                let _ = std::fs::remove_file(format!("{}{}", self.0, suffix));
+            }
+        }
+    }
+
+    fn create_game(manager: &GameManager) -> GameId {
+        manager.create_game(CreateGameRequest {
+            name: "Persistante".to_string(),
+            max_players: 4,
+            ..Default::default()
+        }).unwrap().game_id
+    }
+
+    fn guest(name: &str) -> JoinGameRequest {
+        JoinGameRequest {
+            bot_name: name.to_string(),
+            player_type: PlayerType::Bot,
+            bot_id: None,
+            bot_secret: None,
+            buy_in: None,
+        }
+    }
+
+    fn play(manager: &GameManager, game_id: GameId, players: &[JoinGameResponse], action: PlayerAction) {
+        let current = manager.get_spectator_state(game_id).unwrap().current_player_id.unwrap();
+        let token = players.iter().find(|p| p.player_id == current).unwrap().auth_token.clone();
+        manager.submit_action(game_id, SubmitActionRequest { auth_token: token, action }).unwrap();
+    }
+
+    #[test]
+    fn test_game_in_progress_survives_a_restart() {
+        let db = TempDb::new();
+        let manager = db.manager();
+        let bot = manager.register_bot(RegisterBotRequest { name: "Persistant".to_string() }).unwrap();
+        let game_id = create_game(&manager);
+        let players = [
+            manager.join_game(game_id, JoinGameRequest {
+                bot_id: Some(bot.bot_id.clone()),
+                bot_secret: Some(bot.api_key.clone()),
+                ..guest("")
+            }).unwrap(),
+            manager.join_game(game_id, guest("Bob")).unwrap(),
+        ];
+        manager.start_game(game_id).unwrap();
+
+        // Une main terminée, la suivante en cours
+        play(&manager, game_id, &players, PlayerAction::Fold);
+        play(&manager, game_id, &players, PlayerAction::Call);
+        manager.checkpoint();
+        let before = serde_json::to_value(manager.get_admin_state(game_id).unwrap()).unwrap();
+        drop(manager);
+
+        let restored = db.manager();
+        assert_eq!(serde_json::to_value(restored.get_admin_state(game_id).unwrap()).unwrap(), before);
+        let history = restored.get_game(game_id).unwrap().hand_history;
+        assert_eq!(history.len(), 1);
+        assert!(matches!(history[0].events.last().unwrap().event, HandEvent::PotAwarded { .. }));
+        assert!(history[0].seed.is_some());
+
+        // Les tokens restent valables et la main reprend là où elle en était
+        play(&restored, game_id, &players, PlayerAction::Check);
+        assert_eq!(restored.get_spectator_state(game_id).unwrap().phase, "flop");
+
+        // Le bot enregistré garde son identité et sa clé
+        let other_game = create_game(&restored);
+        let rejoined = restored.join_game(other_game, JoinGameRequest {
+            bot_id: Some(bot.bot_id.clone()),
+            bot_secret: Some(bot.api_key.clone()),
+            ..guest("")
+        }).unwrap();
+        assert_eq!(rejoined.player_id, bot.bot_id);
+    }
+
+    #[test]
+    fn test_tournament_survives_a_restart() {
+        let db = TempDb::new();
+        let manager = db.manager();
+        let tournament_id = manager.create_tournament(CreateTournamentRequest {
+            name: "Persistant".to_string(),
+            table_size: 3,
+            starting_chips: 1000,
+            small_blind: 10,
+            big_blind: 20,
+            ante: 0,
+            blind_levels: vec![],
+            points_table: crate::scoring::default_points_table(),
+        }).unwrap().tournament_id;
+        for name in ["A", "B", "C", "D", "E"] {
+            manager.register_tournament(tournament_id, guest(name)).unwrap();
+        }
+        manager.start_tournament(tournament_id).unwrap();
+        manager.checkpoint();
+        let before = serde_json::to_value(manager.get_tournament(tournament_id).unwrap()).unwrap();
+        drop(manager);
+
+        let restored = db.manager();
+        assert_eq!(serde_json::to_value(restored.get_tournament(tournament_id).unwrap()).unwrap(), before);
+        let tables = restored.get_tournament(tournament_id).unwrap().tables;
+        assert_eq!(tables.len(), 2);
+        for table in tables {
+            let state = restored.get_spectator_state(table.game_id).unwrap();
+            assert_eq!(state.phase, "preflop");
+        }
+    }
+
+    #[test]
+    fn test_duplicate_match_survives_a_restart() {
+        let db = TempDb::new();
+        let manager = db.manager();
+        let duplicate_id = manager.create_duplicate(CreateDuplicateRequest {
+            name: "Persistant".to_string(),
+            starting_chips: 1000,
+            small_blind: 10,
+            big_blind: 20,
+            ante: 0,
+            hands: 10,
+            seed: Some(7),
+        }).unwrap().duplicate_id;
+        let alice = manager.register_duplicate(duplicate_id, guest("Alice")).unwrap();
+        manager.register_duplicate(duplicate_id, guest("Bob")).unwrap();
+        manager.start_duplicate(duplicate_id).unwrap();
+        manager.checkpoint();
+        let before = serde_json::to_value(manager.get_duplicate(duplicate_id).unwrap()).unwrap();
+        drop(manager);
+
+        // Le token du match reste valable à la table en jeu
+        let restored = db.manager();
+        assert_eq!(serde_json::to_value(restored.get_duplicate(duplicate_id).unwrap()).unwrap(), before);
+        let tables = restored.get_duplicate(duplicate_id).unwrap().tables;
+        assert_eq!(tables.len(), 1);
+        assert_eq!(restored.authenticate(tables[0].game_id, &alice.auth_token).unwrap(), alice.player_id);
+    }
+
+    #[test]
+    fn test_archived_and_deleted_games() {
+        let db = TempDb::new();
+        let manager = db.manager();
+        let game_id = create_game(&manager);
+        manager.join_game(game_id, guest("Alice")).unwrap();
+        manager.join_game(game_id, guest("Bob")).unwrap();
+        manager.start_game(game_id).unwrap();
+        manager.abort_game(game_id).unwrap();
+        manager.checkpoint();
+        manager.archive_game(game_id).unwrap();
+        drop(manager);
+
+        let restored = db.manager();
+        assert!(restored.list_games().games.is_empty());
+        assert_eq!(restored.list_archived_games().games.len(), 1);
+        restored.delete_game(game_id).unwrap();
+        drop(restored);
+
+        assert!(db.manager().list_archived_games().games.is_empty());
+    }
+}
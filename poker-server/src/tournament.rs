@@ -0,0 +1,633 @@
+use crate::blind_schedule::BlindLevel;
+use crate::game_manager::GameRoom;
+use crate::models::*;
+use crate::scoring::points_for;
+use poker_engine::{BlindsConfig, PlayerStatus};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Joueur inscrit à un tournoi
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entrant {
+    pub player_id: PlayerId,
+    pub name: String,
+    pub player_type: PlayerType,
+    pub bot_id: Option<BotId>,
+}
+
+/// Tournoi multi-tables
+///
+/// Chaque table est une `GameRoom` ordinaire. Entre deux mains, le tournoi enregistre
+/// les éliminations, déplace des joueurs pour garder des tables équilibrées (écart d'un
+/// joueur au plus), casse les tables devenues inutiles et forme la table finale.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tournament {
+    pub id: TournamentId,
+    pub name: String,
+    pub table_size: usize,
+    pub starting_chips: u32,
+    pub blinds: BlindsConfig,
+    pub blind_levels: Vec<BlindLevel>,
+    pub points_table: Vec<u32>,        // Points attribués à la 1re, 2e... place
+    pub entrants: Vec<Entrant>,        // Ordre d'inscription
+    pub tables: Vec<GameId>,           // Tables en jeu
+    closing_tables: Vec<GameId>,       // Tables cassées qui terminent leur dernière main
+    pub seats: HashMap<PlayerId, GameId>, // Table actuelle des joueurs encore en lice
+    pending_moves: HashMap<PlayerId, GameId>, // Changements de table en attente de la fin de main
+    pub eliminations: Vec<PlayerId>,   // Ordre de sortie (premier éliminé en tête)
+    pub final_table: Option<GameId>,
+    pub started: bool,
+    pub finished: bool,
+}
+
+impl Tournament {
+    pub fn new(
+        id: TournamentId,
+        name: String,
+        table_size: usize,
+        starting_chips: u32,
+        blinds: BlindsConfig,
+        blind_levels: Vec<BlindLevel>,
+        points_table: Vec<u32>,
+    ) -> Self {
+        Tournament {
+            id,
+            name,
+            table_size,
+            starting_chips,
+            blinds,
+            blind_levels,
+            points_table,
+            entrants: Vec::new(),
+            tables: Vec::new(),
+            closing_tables: Vec::new(),
+            seats: HashMap::new(),
+            pending_moves: HashMap::new(),
+            eliminations: Vec::new(),
+            final_table: None,
+            started: false,
+            finished: false,
+        }
+    }
+
+    /// Inscrire un joueur (avant le début du tournoi)
+    pub fn register(&mut self, entrant: Entrant) -> Result<(), String> {
+        if self.started {
+            return Err("Le tournoi a déjà commencé".to_string());
+        }
+
+        if self.entrants.iter().any(|e| e.player_id == entrant.player_id) {
+            return Err("Ce joueur est déjà inscrit".to_string());
+        }
+
+        let name = entrant.name.clone();
+        self.entrants.push(entrant);
+
+        println!("✅ {} s'est inscrit au tournoi {} ({} inscrits)", name, self.name, self.entrants.len());
+        Ok(())
+    }
+
+    /// Créer les tables, y répartir les inscrits et lancer la première main partout
+    pub fn start(&mut self, games: &mut HashMap<GameId, GameRoom>) -> Result<(), String> {
+        if self.started {
+            return Err("Le tournoi a déjà commencé".to_string());
+        }
+
+        if self.entrants.len() < 2 {
+            return Err("Il faut au moins 2 joueurs pour commencer".to_string());
+        }
+
+        let num_tables = self.entrants.len().div_ceil(self.table_size);
+        let mut rooms: Vec<GameRoom> = (0..num_tables)
+            .map(|i| {
+                let mut room = GameRoom::new(
+                    Uuid::new_v4(),
+                    format!("{} - Table {}", self.name, i + 1),
+                    self.table_size,
+                    self.starting_chips,
+                    self.blinds,
+                );
+                room.tournament_id = Some(self.id);
+                room.set_blind_levels(self.blind_levels.clone());
+                room
+            })
+            .collect();
+
+        // Distribution en tourniquet : les tables ont au plus un joueur d'écart
+        for (i, entrant) in self.entrants.iter().enumerate() {
+            let room = &mut rooms[i % num_tables];
+            room.add_player(entrant.player_id.clone(), entrant.name.clone(), entrant.player_type, None)?;
+            self.seats.insert(entrant.player_id.clone(), room.id);
+        }
+
+        for mut room in rooms {
+            room.start_game()?;
+            self.tables.push(room.id);
+            games.insert(room.id, room);
+        }
+
+        self.started = true;
+        if self.tables.len() == 1 {
+            self.final_table = self.tables.first().copied();
+        }
+
+        println!("🏁 Tournoi {} démarré : {} joueurs sur {} tables", self.name, self.entrants.len(), num_tables);
+        Ok(())
+    }
+
+    /// Faire avancer le tournoi après une action ou pendant la maintenance
+    ///
+    /// Retourne true si un joueur a été éliminé, déplacé ou si une table a été cassée.
+    pub fn update(&mut self, games: &mut HashMap<GameId, GameRoom>) -> bool {
+        if !self.started || self.finished {
+            return false;
+        }
+
+        let mut changed = self.record_eliminations(games);
+
+        if self.seats.len() <= 1 {
+            self.finish(games);
+            return true;
+        }
+
+        changed |= self.balance_tables(games);
+        changed |= self.seat_moved_players(games);
+        self.close_empty_tables(games);
+
+        if self.tables.len() == 1 && self.final_table.is_none() {
+            self.final_table = self.tables.first().copied();
+            if let Some(room) = self.final_table.and_then(|id| games.get_mut(&id)) {
+                println!("🏆 Table finale du tournoi {} : {}", self.name, room.name);
+                room.state.note("🏆 Final table");
+            }
+        }
+
+        self.resume_waiting_tables(games);
+        changed
+    }
+
+    /// Enregistrer les joueurs qui n'ont plus de jetons
+    ///
+    /// Les joueurs éliminés pendant la même main sortent du plus petit au plus gros tapis de début de main.
+    fn record_eliminations(&mut self, games: &mut HashMap<GameId, GameRoom>) -> bool {
+        let mut changed = false;
+
+        let table_ids: Vec<GameId> = self.tables.iter().chain(&self.closing_tables).copied().collect();
+        for table_id in table_ids {
+            let Some(room) = games.get_mut(&table_id) else {
+                continue;
+            };
+
+            let mut busted: Vec<PlayerId> = room.state.players.iter()
+                .filter(|p| p.chips == 0 && p.status == PlayerStatus::Eliminated)
+                .filter(|p| self.seats.get(&p.id) == Some(&table_id))
+                .map(|p| p.id.clone())
+                .collect();
+            room.sort_busts(&mut busted);
+
+            for player_id in busted {
+                self.eliminate(&player_id, room);
+                changed = true;
+            }
+        }
+
+        changed
+    }
+
+    /// Sortir un joueur du tournoi et lui attribuer sa place
+    fn eliminate(&mut self, player_id: &PlayerId, room: &mut GameRoom) {
+        let position = self.entrants.len() - self.eliminations.len();
+        self.seats.remove(player_id);
+        self.pending_moves.remove(player_id);
+        self.eliminations.push(player_id.clone());
+        let _ = room.state.remove_player(player_id);
+        room.record_finish(player_id, position);
+
+        let name = self.entrant_name(player_id);
+        println!("💀 {} éliminé du tournoi {} ({}e)", name, self.name, position);
+        room.state.note(format!("💀 {} busted ({}th)", name, position));
+    }
+
+    /// Désigner le vainqueur et clore toutes les tables
+    fn finish(&mut self, games: &mut HashMap<GameId, GameRoom>) {
+        self.finished = true;
+        let winner_id = self.seats.keys().next().cloned();
+
+        for table_id in self.tables.iter().chain(&self.closing_tables) {
+            if let Some(room) = games.get_mut(table_id) {
+                room.game_finished = true;
+                if let Some(winner_id) = winner_id.as_ref().filter(|id| self.seats.get(*id) == Some(table_id)) {
+                    room.winner_id = Some(winner_id.clone());
+                    room.record_finish(winner_id, 1);
+                    room.publish_end();
+                }
+            }
+        }
+
+        if let Some(winner_id) = winner_id {
+            println!("🏆 TOURNOI {} TERMINÉ - Vainqueur: {}", self.name, self.entrant_name(&winner_id));
+        }
+    }
+
+    /// Casser les tables en trop puis équilibrer les tables restantes
+    fn balance_tables(&mut self, games: &mut HashMap<GameId, GameRoom>) -> bool {
+        let mut changed = false;
+
+        loop {
+            let counts = self.table_counts();
+            let Some(&(smallest, min)) = counts.iter().min_by_key(|(_, count)| *count) else {
+                return changed;
+            };
+            let Some(&(largest, max)) = counts.iter().max_by_key(|(_, count)| *count) else {
+                return changed;
+            };
+
+            // Les joueurs restants tiennent sur une table de moins : on casse la plus petite
+            if self.tables.len() > 1 && self.seats.len() <= (self.tables.len() - 1) * self.table_size {
+                self.break_table(smallest, games);
+                changed = true;
+                continue;
+            }
+
+            if max <= min + 1 {
+                return changed;
+            }
+
+            // Les joueurs déjà en partance sont déplacés en dernier recours
+            let Some(player_id) = self.players_heading_to(largest)
+                .into_iter()
+                .min_by_key(|id| (self.pending_moves.contains_key(id), id.clone()))
+            else {
+                return changed;
+            };
+            self.move_player(&player_id, smallest, games);
+            changed = true;
+        }
+    }
+
+    /// Répartir les joueurs d'une table sur les tables restantes
+    fn break_table(&mut self, table_id: GameId, games: &mut HashMap<GameId, GameRoom>) {
+        self.tables.retain(|id| *id != table_id);
+        self.closing_tables.push(table_id);
+
+        if let Some(room) = games.get_mut(&table_id) {
+            println!("🔨 Table cassée : {} ({} tables restantes)", room.name, self.tables.len());
+            room.state.note("🔨 Table broken, players are moved");
+        }
+
+        let mut players = self.players_heading_to(table_id);
+        players.sort();
+        for player_id in players {
+            let destination = self.table_counts()
+                .into_iter()
+                .min_by_key(|(_, count)| *count)
+                .map(|(id, _)| id);
+            if let Some(destination) = destination {
+                self.move_player(&player_id, destination, games);
+            }
+        }
+    }
+
+    /// Programmer le départ d'un joueur vers une autre table (effectif à la fin de sa main)
+    fn move_player(&mut self, player_id: &PlayerId, destination: GameId, games: &mut HashMap<GameId, GameRoom>) {
+        let Some(&current) = self.seats.get(player_id) else {
+            return;
+        };
+        if current == destination && !self.pending_moves.contains_key(player_id) {
+            return;
+        }
+
+        self.pending_moves.insert(player_id.clone(), destination);
+        if let Some(room) = games.get_mut(&current) {
+            let _ = room.state.remove_player(player_id);
+        }
+    }
+
+    /// Asseoir à leur nouvelle table les joueurs qui ont quitté la leur
+    fn seat_moved_players(&mut self, games: &mut HashMap<GameId, GameRoom>) -> bool {
+        let mut arrivals = Vec::new();
+        let table_ids: Vec<GameId> = self.tables.iter().chain(&self.closing_tables).copied().collect();
+        for table_id in table_ids {
+            let Some(room) = games.get_mut(&table_id) else {
+                continue;
+            };
+            for player in room.state.take_removed_players() {
+                // Les joueurs éliminés quittent simplement la table
+                if !self.pending_moves.contains_key(&player.id) {
+                    continue;
+                }
+                if player.chips == 0 {
+                    // Tapis perdu pendant sa dernière main à cette table
+                    self.eliminate(&player.id, room);
+                    continue;
+                }
+                if let Some(destination) = self.pending_moves.remove(&player.id) {
+                    let player_type = room.player_types.get(&player.id).copied().unwrap_or(PlayerType::Bot);
+                    arrivals.push((player, player_type, destination));
+                }
+            }
+        }
+
+        let changed = !arrivals.is_empty();
+        for (player, player_type, destination) in arrivals {
+            if let Some(room) = games.get_mut(&destination) {
+                self.seats.insert(player.id.clone(), destination);
+                room.seat_player(player, player_type);
+            }
+        }
+        changed
+    }
+
+    /// Clore les tables cassées une fois tous leurs joueurs partis
+    fn close_empty_tables(&mut self, games: &mut HashMap<GameId, GameRoom>) {
+        let seats = &self.seats;
+        self.closing_tables.retain(|table_id| {
+            let occupied = seats.values().any(|id| id == table_id);
+            if !occupied {
+                if let Some(room) = games.get_mut(table_id) {
+                    room.game_finished = true;
+                }
+            }
+            occupied
+        });
+    }
+
+    /// Relancer les tables restées sans adversaire une fois des joueurs arrivés
+    fn resume_waiting_tables(&self, games: &mut HashMap<GameId, GameRoom>) {
+        for table_id in &self.tables {
+            let Some(room) = games.get_mut(table_id) else {
+                continue;
+            };
+            room.start_next_hand_if_ready();
+        }
+    }
+
+    /// Nombre de joueurs par table en jeu, en comptant les déplacements programmés
+    fn table_counts(&self) -> Vec<(GameId, usize)> {
+        self.tables
+            .iter()
+            .map(|&table_id| (table_id, self.players_heading_to(table_id).len()))
+            .collect()
+    }
+
+    /// Joueurs qui joueront la prochaine main à cette table
+    fn players_heading_to(&self, table_id: GameId) -> Vec<PlayerId> {
+        self.seats
+            .iter()
+            .filter(|(player_id, seat)| *self.pending_moves.get(*player_id).unwrap_or(seat) == table_id)
+            .map(|(player_id, _)| player_id.clone())
+            .collect()
+    }
+
+    /// Nom d'un inscrit
+    fn entrant_name(&self, player_id: &PlayerId) -> String {
+        self.entrants
+            .iter()
+            .find(|e| &e.player_id == player_id)
+            .map(|e| e.name.clone())
+            .unwrap_or_else(|| player_id.clone())
+    }
+
+    /// Places finales connues et points du barème, de la meilleure à la moins bonne
+    pub fn finishing_positions(&self) -> Vec<FinishingPosition> {
+        let total = self.entrants.len();
+        let winner = if self.finished { self.seats.keys().next() } else { None };
+
+        winner
+            .into_iter()
+            .chain(self.eliminations.iter().rev())
+            .map(|player_id| {
+                let position = match self.eliminations.iter().position(|id| id == player_id) {
+                    Some(order) => total - order,
+                    None => 1,
+                };
+                FinishingPosition {
+                    position,
+                    player_id: player_id.clone(),
+                    name: self.entrant_name(player_id),
+                    points: points_for(&self.points_table, position),
+                    bot_id: self.entrants.iter()
+                        .find(|e| &e.player_id == player_id)
+                        .and_then(|e| e.bot_id.clone()),
+                }
+            })
+            .collect()
+    }
+
+    /// Classement du tournoi au format API
+    pub fn standings(&self) -> TournamentStandingsResponse {
+        TournamentStandingsResponse {
+            tournament_id: self.id,
+            name: self.name.clone(),
+            finished: self.finished,
+            standings: self.finishing_positions(),
+        }
+    }
+
+    /// État du tournoi au format API
+    pub fn status(&self, games: &HashMap<GameId, GameRoom>) -> TournamentStatusResponse {
+        TournamentStatusResponse {
+            tournament_id: self.id,
+            name: self.name.clone(),
+            started: self.started,
+            finished: self.finished,
+            players_registered: self.entrants.len(),
+            players_remaining: self.seats.len(),
+            tables: self.tables.iter().map(|&table_id| {
+                let mut players = self.players_heading_to(table_id);
+                players.sort();
+                TournamentTableInfo {
+                    game_id: table_id,
+                    name: games.get(&table_id).map(|room| room.name.clone()).unwrap_or_default(),
+                    players,
+                }
+            }).collect(),
+            final_table: self.final_table,
+            finishing_positions: self.finishing_positions(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poker_engine::HandResult;
+
+    fn tournament(entrants: usize, table_size: usize) -> (Tournament, HashMap<GameId, GameRoom>) {
+        let mut tournament = Tournament::new(
+            Uuid::new_v4(),
+            "Test".to_string(),
+            table_size,
+            1000,
+            BlindsConfig::new(10, 20),
+            vec![],
+            crate::scoring::default_points_table(),
+        );
+        for i in 0..entrants {
+            tournament.register(Entrant {
+                player_id: format!("Bot{}_id", i),
+                name: format!("Bot{}", i),
+                player_type: PlayerType::Bot,
+                bot_id: None,
+            }).unwrap();
+        }
+        let mut games = HashMap::new();
+        tournament.start(&mut games).unwrap();
+        (tournament, games)
+    }
+
+    /// Faire perdre tous ses jetons à un joueur et passer à la main suivante
+    fn bust(games: &mut HashMap<GameId, GameRoom>, table_id: GameId, player_id: &PlayerId) {
+        let room = games.get_mut(&table_id).unwrap();
+        let player = room.state.players.iter_mut().find(|p| &p.id == player_id).unwrap();
+        player.chips = 0;
+        room.state.start_new_hand();
+    }
+
+    /// Archiver la main en cours, comme à sa fin, pour garder trace des tapis de départ
+    fn archive_hand(room: &mut GameRoom) {
+        room.hand_history.push(HandResult {
+            hand_number: room.state.hand_number,
+            board: vec![],
+            pots: vec![],
+            revealed_hands: vec![],
+            events: room.state.hand_events().to_vec(),
+            seed: None,
+        });
+    }
+
+    fn seated(games: &HashMap<GameId, GameRoom>, table_id: GameId) -> usize {
+        games[&table_id].state.players.len()
+    }
+
+    #[test]
+    fn test_entrants_are_spread_across_tables() {
+        let (tournament, games) = tournament(7, 3);
+
+        assert_eq!(tournament.tables.len(), 3);
+        let mut sizes: Vec<_> = tournament.tables.iter().map(|&id| seated(&games, id)).collect();
+        sizes.sort();
+        assert_eq!(sizes, vec![2, 2, 3]);
+        assert!(games.values().all(|room| room.started && room.tournament_id == Some(tournament.id)));
+        assert_eq!(tournament.final_table, None);
+    }
+
+    #[test]
+    fn test_table_is_broken_when_players_fit_on_fewer_tables() {
+        let (mut tournament, mut games) = tournament(5, 3);
+        let short_table = *tournament.tables.iter().find(|&&id| seated(&games, id) == 2).unwrap();
+        let other_table = *tournament.tables.iter().find(|&&id| id != short_table).unwrap();
+
+        // Un joueur sort : la table courte attend, faute d'adversaire
+        let busted = games[&short_table].state.players[0].id.clone();
+        let survivor = games[&short_table].state.players[1].id.clone();
+        bust(&mut games, short_table, &busted);
+        assert!(!games[&short_table].state.is_hand_in_progress());
+
+        assert!(tournament.update(&mut games));
+
+        // 4 joueurs restants : toujours 2 tables, mais un joueur rejoint la table courte
+        assert_eq!(tournament.eliminations, vec![busted.clone()]);
+        assert_eq!(tournament.tables.len(), 2);
+        assert_eq!(tournament.pending_moves.len(), 1);
+
+        // Un second joueur sort à l'autre table : les 3 restants forment la table finale
+        let busted_2 = games[&other_table].state.players.iter()
+            .find(|p| p.chips > 0 && !tournament.pending_moves.contains_key(&p.id))
+            .map(|p| p.id.clone())
+            .unwrap();
+        bust(&mut games, other_table, &busted_2);
+        assert!(tournament.update(&mut games));
+
+        assert_eq!(tournament.tables.len(), 1);
+        let final_table = tournament.final_table.unwrap();
+        assert_eq!(tournament.seats.get(&survivor), Some(&final_table));
+        assert_eq!(tournament.status(&games).tables[0].players.len(), 3);
+
+        let positions = tournament.finishing_positions();
+        assert_eq!(positions[0].position, 4);
+        assert_eq!(positions[0].player_id, busted_2);
+        assert_eq!(positions[1].position, 5);
+        assert_eq!(positions[1].player_id, busted);
+    }
+
+    #[test]
+    fn test_tables_are_rebalanced() {
+        let (mut tournament, mut games) = tournament(8, 4);
+        let (table_a, table_b) = (tournament.tables[0], tournament.tables[1]);
+
+        // Table A perd deux joueurs : 2 contre 4, un joueur de B doit changer de table
+        let ids: Vec<_> = games[&table_a].state.players.iter().map(|p| p.id.clone()).collect();
+        {
+            let room = games.get_mut(&table_a).unwrap();
+            room.state.players[0].chips = 0;
+            room.state.players[1].chips = 0;
+            room.state.start_new_hand();
+        }
+        tournament.update(&mut games);
+
+        assert_eq!(tournament.eliminations.len(), 2);
+        assert!(tournament.eliminations.contains(&ids[0]));
+        let counts = tournament.table_counts();
+        assert!(counts.iter().all(|&(_, count)| count == 3));
+
+        // Le joueur déplacé quitte B à la fin de sa main et arrive à A
+        games.get_mut(&table_b).unwrap().state.start_new_hand();
+        tournament.update(&mut games);
+        assert_eq!(seated(&games, table_b), 3);
+        assert!(tournament.pending_moves.is_empty());
+        assert_eq!(tournament.players_heading_to(table_a).len(), 3);
+    }
+
+    #[test]
+    fn test_players_busting_in_the_same_hand_are_ranked_by_starting_stack() {
+        let (mut tournament, mut games) = tournament(3, 6);
+        let table_id = tournament.tables[0];
+        let ids: Vec<_> = games[&table_id].state.players.iter().map(|p| p.id.clone()).collect();
+
+        // Le premier siège commence la main avec le plus gros des deux tapis
+        {
+            let room = games.get_mut(&table_id).unwrap();
+            room.state.players[0].chips = 500;
+            room.state.players[1].chips = 300;
+            room.state.start_new_hand();
+            archive_hand(room);
+            room.state.players[0].chips = 0;
+            room.state.players[1].chips = 0;
+            room.state.start_new_hand();
+        }
+        tournament.update(&mut games);
+
+        assert!(tournament.finished);
+        let positions: Vec<_> = tournament.finishing_positions().into_iter()
+            .map(|f| (f.player_id, f.position))
+            .collect();
+        assert_eq!(positions, vec![(ids[2].clone(), 1), (ids[0].clone(), 2), (ids[1].clone(), 3)]);
+    }
+
+    #[test]
+    fn test_last_player_standing_wins() {
+        let (mut tournament, mut games) = tournament(2, 6);
+        let table_id = tournament.tables[0];
+        assert_eq!(tournament.final_table, Some(table_id));
+
+        let loser = games[&table_id].state.players[1].id.clone();
+        let winner = games[&table_id].state.players[0].id.clone();
+        bust(&mut games, table_id, &loser);
+        tournament.update(&mut games);
+
+        assert!(tournament.finished);
+        assert!(games[&table_id].game_finished);
+        assert_eq!(games[&table_id].winner_id, Some(winner.clone()));
+        let positions = tournament.finishing_positions();
+        assert_eq!(positions[0].player_id, winner);
+        assert_eq!(positions[0].position, 1);
+        assert_eq!(positions[0].points, 10);
+        assert_eq!(positions[1].position, 2);
+        assert_eq!(positions[1].points, 6);
+
+        // Les places sont aussi enregistrées sur la table
+        assert_eq!(games[&table_id].finishing_positions, vec![(loser, 2), (winner, 1)]);
+    }
+}
@@ -4,6 +4,7 @@ let pollInterval = null;
 let humanPlayerId = null;
 let humanAuthToken = null;
 let isHumanPlayer = false;
+let adminToken = sessionStorage.getItem('pokerAdminToken');
 
 // Éléments DOM
 const lobby = document.getElementById('lobby');
@@ -109,7 +110,7 @@ async function createGame(e) {
     };
 
     try {
-        const response = await fetch('/api/games', {
+        const response = await adminFetch('/api/games', {
             method: 'POST',
             headers: { 'Content-Type': 'application/json' },
             body: JSON.stringify(gameData)
@@ -125,7 +126,7 @@ async function createGame(e) {
         }
     } catch (error) {
         console.error('Erreur:', error);
-        alert('Erreur de connexion au serveur');
+        alert(error.message === 'Droits administrateur requis' ? error.message : 'Erreur de connexion au serveur');
     }
 }
 
@@ -198,13 +199,13 @@ async function updateGameState() {
     const expectedGameId = currentGameId;
 
     try {
-        // Utiliser le player_id du joueur humain si disponible, sinon mode spectateur
-        const playerId = humanPlayerId || 'spectator';
-        const response = await fetch(`/api/games/${expectedGameId}/state?player_id=${playerId}`);
+        // Le token du joueur humain l'identifie ; sans token, vue spectateur (sans cartes privées)
+        const headers = humanAuthToken ? { 'Authorization': `Bearer ${humanAuthToken}` } : {};
+        const response = await fetch(`/api/games/${expectedGameId}/state`, { headers });
 
         if (!response.ok) {
-            // La partie n'existe peut-être plus
-            if (response.status === 400) {
+            // La partie n'existe peut-être plus (ou le token a expiré)
+            if (response.status === 400 || response.status === 401) {
                 // Vérifier si on est toujours sur la même partie avant de quitter
                 if (currentGameId === expectedGameId) {
                     addLogEntry('Partie terminée ou introuvable', 'system');
@@ -231,6 +232,7 @@ async function updateGameState() {
 
 
 let hasDismissedWinnerPopup = false;
+let lastShownHandNumber = null;
 
 function renderGameState(state) {
     // Mettre à jour l'interface du joueur humain si présent
@@ -239,7 +241,9 @@ function renderGameState(state) {
     }
 
     // Afficher le gagnant de la main précédente si disponible
-    if (state.last_hand_winner && !isShowingWinner) {
+    const lastHandNumber = state.last_hand_result ? state.last_hand_result.hand_number : null;
+    if (state.last_hand_winner && !isShowingWinner && lastHandNumber !== lastShownHandNumber) {
+        lastShownHandNumber = lastHandNumber;
         showHandWinner(state.last_hand_winner_name, state.last_hand_amount, state.last_hand_description, state.last_hand_cards);
     }
 
@@ -267,7 +271,7 @@ function renderGameState(state) {
     }
 
     // Joueurs - IMPORTANT: ne pas tout re-render à chaque fois
-    renderPlayers(state.players, state.current_player_id, state.action_log);
+    renderPlayers(state.players, state.current_player_id, state.hand_events);
 
     // Désactiver le bouton start si la partie a démarré
     const startBtn = document.getElementById('startGameBtn');
@@ -398,7 +402,7 @@ function renderCommunityCards(cards) {
     }).join('');
 }
 
-function renderPlayers(players, currentPlayerId, actionLog) {
+function renderPlayers(players, currentPlayerId, handEvents) {
     const container = document.getElementById('playersContainer');
 
     // Si le nombre de joueurs a changé, on doit tout re-créer
@@ -406,7 +410,7 @@ function renderPlayers(players, currentPlayerId, actionLog) {
     if (existingPlayers.length !== players.length) {
         container.innerHTML = '';
         players.forEach((player, index) => {
-            const playerDiv = createPlayerElement(player, currentPlayerId, actionLog);
+            const playerDiv = createPlayerElement(player, currentPlayerId, handEvents);
             playerDiv.dataset.playerId = player.id;
             container.appendChild(playerDiv);
         });
@@ -416,25 +420,25 @@ function renderPlayers(players, currentPlayerId, actionLog) {
             let playerDiv = container.querySelector(`[data-player-id="${player.id}"]`);
             if (!playerDiv) {
                 // Si le joueur n'existe pas, le créer
-                playerDiv = createPlayerElement(player, currentPlayerId, actionLog);
+                playerDiv = createPlayerElement(player, currentPlayerId, handEvents);
                 playerDiv.dataset.playerId = player.id;
                 container.appendChild(playerDiv);
             } else {
                 // Mettre à jour seulement ce qui a changé
-                updatePlayerElement(playerDiv, player, currentPlayerId, actionLog);
+                updatePlayerElement(playerDiv, player, currentPlayerId, handEvents);
             }
         });
     }
 }
 
-function createPlayerElement(player, currentPlayerId, actionLog) {
+function createPlayerElement(player, currentPlayerId, handEvents) {
     const div = document.createElement('div');
     div.className = 'player';
-    updatePlayerElement(div, player, currentPlayerId, actionLog);
+    updatePlayerElement(div, player, currentPlayerId, handEvents);
     return div;
 }
 
-function updatePlayerElement(playerDiv, player, currentPlayerId, actionLog) {
+function updatePlayerElement(playerDiv, player, currentPlayerId, handEvents) {
     const isActive = player.id === currentPlayerId;
     const isFolded = player.status === 'Folded';
 
@@ -464,7 +468,7 @@ function updatePlayerElement(playerDiv, player, currentPlayerId, actionLog) {
     }
 
     // Récupérer la dernière action
-    const lastAction = getPlayerLastAction(player.name, actionLog);
+    const lastAction = getPlayerLastAction(player.id, handEvents);
 
     const newHtml = `
         <div class="player-last-action">${lastAction}</div>
@@ -486,44 +490,14 @@ function isRedCard(card) {
     return card.includes('♥') || card.includes('♦');
 }
 
-function getPlayerLastAction(playerName, actionLog) {
-    if (!actionLog || !playerName) return '';
-
-    // Chercher la dernière action de ce joueur dans les logs (parcourir à l'envers)
-    // Format attendu: "[phase] PlayerName -> ACTION ..."
-
-    for (let i = actionLog.length - 1; i >= 0; i--) {
-        const log = actionLog[i];
-
-        // Vérifier si la ligne concerne ce joueur
-        // On cherche " PlayerName -> " pour être sûr
-        const pattern = ` ${playerName} -> `;
-        const index = log.indexOf(pattern);
-
-        if (index !== -1) {
-            // Extraire l'action après la flèche
-            let actionPart = log.substring(index + pattern.length);
-
-            // L'action peut être "CALL (50)", "RAISE (100)", "FOLD", etc.
-            // On veut juste le premier mot ou "ALL-IN"
+function getPlayerLastAction(playerId, handEvents) {
+    if (!handEvents || !playerId) return '';
 
-            // Nettoyer pour affichage
-            if (actionPart.includes('FOLD')) return 'FOLD';
-            if (actionPart.includes('CHECK')) return 'CHECK';
-            if (actionPart.includes('CALL')) return 'CALL';
-            if (actionPart.includes('RAISE')) return 'RAISE';
-            if (actionPart.includes('ALL-IN')) return 'ALL-IN';
-
-            // Fallback: retourner le début de la chaîne
-            return actionPart.split(' ')[0];
-        }
-
-        // Support pour l'ancien format au cas où (legacy)
-        if (log.startsWith(playerName + ' ')) {
-            const parts = log.substring(playerName.length + 1).split(' ');
-            let action = parts[0];
-            if (action.endsWith('s')) action = action.slice(0, -1);
-            return action.toUpperCase();
+    // Dernière décision de ce joueur dans le journal de la main (parcourir à l'envers)
+    for (let i = handEvents.length - 1; i >= 0; i--) {
+        const event = handEvents[i].event;
+        if (event.type === 'action_taken' && event.player === playerId) {
+            return event.all_in ? 'ALL-IN' : event.action.toUpperCase();
         }
     }
 
@@ -548,6 +522,46 @@ function addLogEntry(message, type = 'action') {
     }
 }
 
+// === ADMINISTRATION ===
+
+// Obtenir un token admin (la clé de l'organisateur est demandée une fois par session)
+async function getAdminToken() {
+    if (adminToken) return adminToken;
+
+    const adminKey = prompt('Clé administrateur (affichée au démarrage du serveur) :');
+    if (!adminKey) return null;
+
+    const response = await fetch('/api/admin/token', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ admin_key: adminKey })
+    });
+    if (!response.ok) {
+        alert('Clé administrateur invalide');
+        return null;
+    }
+
+    adminToken = (await response.json()).auth_token;
+    sessionStorage.setItem('pokerAdminToken', adminToken);
+    return adminToken;
+}
+
+// Requête réservée à l'organisateur ; redemande la clé si le token a expiré
+async function adminFetch(url, options = {}, retry = true) {
+    const token = await getAdminToken();
+    if (!token) throw new Error('Droits administrateur requis');
+
+    const headers = { ...(options.headers || {}), 'Authorization': `Bearer ${token}` };
+    const response = await fetch(url, { ...options, headers });
+
+    if ((response.status === 401 || response.status === 403) && retry) {
+        adminToken = null;
+        sessionStorage.removeItem('pokerAdminToken');
+        return adminFetch(url, options, false);
+    }
+    return response;
+}
+
 // === CONTRÔLES DE PARTIE ===
 
 // Démarrer une partie
@@ -570,7 +584,7 @@ async function startGame(gameId) {
 
     try {
         console.log('🎮 Envoi de la demande de démarrage pour:', gameId);
-        const response = await fetch(`/api/games/${gameId}/start`, {
+        const response = await adminFetch(`/api/games/${gameId}/start`, {
             method: 'POST'
         });
 
@@ -587,7 +601,7 @@ async function startGame(gameId) {
         }
     } catch (error) {
         console.error('Erreur:', error);
-        alert('Erreur de connexion au serveur');
+        alert(error.message === 'Droits administrateur requis' ? error.message : 'Erreur de connexion au serveur');
         // Réactiver si erreur
         startBtn.disabled = false;
         startBtn.textContent = '🎮 Démarrer la Partie';